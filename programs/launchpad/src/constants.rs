@@ -8,3 +8,82 @@ pub const Y_GRID: u8 = 10;
 
 #[constant]
 pub const ALLOCATION_PERCENTAGE: u64 = 1_000_000_000_000_000_000;
+
+#[constant]
+pub const MAX_BURN_RATE: u8 = 100;
+
+#[constant]
+pub const MAX_REFUND_RATE: u8 = 100;
+
+#[constant]
+pub const MAX_GOGR_FEE: u64 = 1_000_000_000_000_000;
+
+#[constant]
+pub const MAX_TOKEN_POOL_HISTORY: usize = 16;
+
+#[constant]
+pub const BOOST_DURATION: i64 = 7 * 24 * 60 * 60;
+
+#[constant]
+pub const GAME_TIMEOUT: i64 = 24 * 60 * 60;
+
+#[constant]
+pub const MAX_FEATURED_POOLS: usize = 10;
+
+#[constant]
+pub const MAX_EXTRA_REWARDS: usize = 3;
+
+#[constant]
+pub const MAX_POOL_NFTS: usize = 10;
+
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+#[constant]
+pub const MAX_REFERRAL_BPS: u16 = 5_000;
+
+#[constant]
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+
+#[constant]
+pub const MAX_TOURNAMENT_POOLS: usize = 5;
+
+#[constant]
+pub const MAX_TOURNAMENT_WINNERS: usize = 5;
+
+#[constant]
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+#[constant]
+pub const MAX_STREAK_DAYS: u16 = 7;
+
+#[constant]
+pub const MAX_DAILY_BONUS_BPS: u16 = 2_000;
+
+#[constant]
+pub const MAX_STAKE_TIERS: usize = 4;
+
+#[constant]
+pub const MAX_COOLDOWN_REDUCTION_BPS: u16 = 9_000;
+
+#[constant]
+pub const MAX_STAKING_FEE_RATE: u8 = 100;
+
+#[constant]
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+// Launchpad::paused bitflags
+// create: pool setup, pool admin and staking deposits
+// mine: games, tournament entries and referrer registration
+// collect: anything moving funds out of pools, tournaments or stakes
+#[constant]
+pub const PAUSE_CREATE: u8 = 1 << 0;
+
+#[constant]
+pub const PAUSE_MINE: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_COLLECT: u8 = 1 << 2;
+
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_CREATE | PAUSE_MINE | PAUSE_COLLECT;
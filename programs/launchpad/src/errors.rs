@@ -38,4 +38,74 @@ pub enum LaunchpadErrorCode {
     TokenExistErr,
     #[msg("admin error")]
     AdminErr,
+    #[msg("math overflow")]
+    MathOverflow,
+    #[msg("timestamp overflow")]
+    TimestampOverflow,
+    #[msg("burn rate error")]
+    BurnRateErr,
+    #[msg("gogr fee error")]
+    GogrFeeErr,
+    #[msg("gogr mint error")]
+    GogrMintErr,
+    #[msg("gogr receiver error")]
+    GogrReceiverErr,
+    #[msg("manager error")]
+    ManagerErr,
+    #[msg("pending manager error")]
+    PendingManagerErr,
+    #[msg("upgrade authority error")]
+    UpgradeAuthorityErr,
+    #[msg("pool status error")]
+    PoolStatusErr,
+    #[msg("refund rate error")]
+    RefundRateErr,
+    #[msg("reward mint error")]
+    RewardMintErr,
+    #[msg("reward account error")]
+    RewardAccountErr,
+    #[msg("nft error")]
+    NftErr,
+    #[msg("nft collection error")]
+    NftCollectionErr,
+    #[msg("nft gate error")]
+    NftGateErr,
+    #[msg("vesting error")]
+    VestingErr,
+    #[msg("referrer error")]
+    ReferrerErr,
+    #[msg("referral rate error")]
+    ReferralRateErr,
+    #[msg("tournament error")]
+    TournamentErr,
+    #[msg("tournament time error")]
+    TournamentTimeErr,
+    #[msg("daily bonus rate error")]
+    DailyBonusRateErr,
+    #[msg("cooldown error")]
+    CooldownErr,
+    #[msg("stake tier error")]
+    StakeTierErr,
+    #[msg("stake error")]
+    StakeErr,
+    #[msg("staking fee rate error")]
+    StakingFeeRateErr,
+    #[msg("unbonding error")]
+    UnbondingErr,
+    #[msg("attestation error")]
+    AttestationErr,
+    #[msg("cpi or bundled instruction error")]
+    TopLevelErr,
+    #[msg("wallet requirement error")]
+    WalletRequirementErr,
+    #[msg("program paused")]
+    ProgramPaused,
+    #[msg("pool has active games")]
+    ActiveGamesErr,
+    #[msg("pause flag error")]
+    PauseFlagErr,
+    #[msg("game not expired")]
+    GameExpiryErr,
+    #[msg("wallet has a running game")]
+    RunningGameErr,
 }
@@ -0,0 +1,24 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct AcceptManager<'info> {
+    pub pending_manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump,
+        has_one = pending_manager @ LaunchpadErrorCode::PendingManagerErr
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+}
+
+pub fn accept_manager_handler(ctx: Context<AcceptManager>) -> Result<()> {
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.manager = launchpad.pending_manager;
+    launchpad.pending_manager = Pubkey::default();
+    launchpad.validate()
+}
@@ -0,0 +1,133 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::constants::{ALLOCATION_PERCENTAGE, MAX_EXTRA_REWARDS, PAUSE_CREATE};
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AddPoolRewardArgs {
+    pub pool_id: u64,
+    pub token_amount: u64,
+    pub allocation_value: u64,
+    pub allocation_percentage: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: AddPoolRewardArgs)]
+pub struct AddPoolReward<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_reward_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_reward_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> AddPoolReward<'info> {
+    fn transfer_reward_token(&self, amount: u64) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to: self.pool_reward_account.to_account_info(),
+                    from: self.owner_reward_account.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+pub fn add_pool_reward_handler(ctx: Context<AddPoolReward>, args: AddPoolRewardArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let pool = &mut ctx.accounts.pool;
+    let reward_mint = ctx.accounts.reward_mint.key();
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    require!(
+        reward_mint != pool.token_mint,
+        LaunchpadErrorCode::RewardMintErr
+    );
+    require!(
+        args.allocation_percentage < ALLOCATION_PERCENTAGE,
+        LaunchpadErrorCode::ParamErr
+    );
+    require!(args.token_amount > 0, LaunchpadErrorCode::TokenAmtErr);
+
+    // a known mint is topped up and takes the new allocation settings
+    let reward = match pool
+        .extra_rewards
+        .iter_mut()
+        .position(|r| r.mint == reward_mint)
+    {
+        Some(i) => &mut pool.extra_rewards[i],
+        None => {
+            require!(
+                pool.extra_rewards.len() < MAX_EXTRA_REWARDS,
+                LaunchpadErrorCode::RewardMintErr
+            );
+            pool.extra_rewards.push(ExtraReward {
+                mint: reward_mint,
+                token_amount: 0,
+                available_token_amount: 0,
+                allocation_value: 0,
+                allocation_percentage: 0,
+            });
+            pool.extra_rewards.last_mut().unwrap()
+        }
+    };
+    reward.allocation_value = args.allocation_value;
+    reward.allocation_percentage = args.allocation_percentage;
+    reward.token_amount = reward
+        .token_amount
+        .checked_add(args.token_amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    reward.available_token_amount = reward
+        .available_token_amount
+        .checked_add(args.token_amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    ctx.accounts.transfer_reward_token(args.token_amount)
+}
@@ -0,0 +1,185 @@
+use crate::constants::PAUSE_CREATE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ApprovePoolArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ApprovePoolArgs)]
+pub struct ApprovePool<'info> {
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump,
+        has_one = manager @ LaunchpadErrorCode::AdminErr
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut, address = launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK:
+    #[account(address = launchpad.gogr_receiver)]
+    pub gogr_receiver: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = gogr_receiver
+    )]
+    pub gogr_receiver_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        seeds = [FEATURED_POOLS_SEED, launchpad.key().as_ref()],
+        bump,
+        space = 8 + FeaturedPools::INIT_SPACE
+    )]
+    pub featured_pools: Box<Account<'info, FeaturedPools>>,
+
+    #[account(
+        mut,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_gogr_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // required while gogr is staked, see Launchpad::add_staking_reward
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> ApprovePool<'info> {
+    fn transfer_gogr(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let pool_gogr_account = self
+            .pool_gogr_account
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::ParamErr)?;
+        let pool_id_bytes = self.pool.pool_id.to_be_bytes();
+        let signer_seeds = [
+            POOL_INFO_SEED,
+            self.pool.launchpad.as_ref(),
+            pool_id_bytes.as_ref(),
+            &[self.pool.bump],
+        ];
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to,
+                    from: pool_gogr_account.to_account_info(),
+                    authority: self.pool.to_account_info(),
+                },
+            )
+            .with_signer(&[&signer_seeds]),
+            amount,
+        )
+    }
+
+    fn burn_gogr(&self, amount: u64) -> Result<()> {
+        let pool_gogr_account = self
+            .pool_gogr_account
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::ParamErr)?;
+        let pool_id_bytes = self.pool.pool_id.to_be_bytes();
+        let signer_seeds = [
+            POOL_INFO_SEED,
+            self.pool.launchpad.as_ref(),
+            pool_id_bytes.as_ref(),
+            &[self.pool.bump],
+        ];
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.gogr_mint.to_account_info(),
+                    from: pool_gogr_account.to_account_info(),
+                    authority: self.pool.to_account_info(),
+                },
+            )
+            .with_signer(&[&signer_seeds]),
+            amount,
+        )
+    }
+}
+
+pub fn approve_pool_handler(ctx: Context<ApprovePool>, _args: ApprovePoolArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    pool.status = PoolStatus::Active;
+
+    // the boost paid at creation starts once the pool is live
+    let boost_amt = pool.boost_escrow;
+    pool.boost_escrow = 0;
+    if boost_amt > 0 {
+        let clock = Clock::get()?;
+        let cur = clock.unix_timestamp;
+        pool.add_boost(boost_amt, cur)?;
+        let featured_pools = &mut ctx.accounts.featured_pools;
+        featured_pools.init(ctx.bumps.featured_pools, ctx.accounts.launchpad.key());
+        featured_pools.update(pool.key(), pool, cur);
+    }
+
+    // settle the escrowed gogr as create_pool would have
+    let gogr_amt = pool.gogr_escrow;
+    pool.gogr_escrow = 0;
+    if gogr_amt > 0 {
+        let burn_amt = ctx.accounts.launchpad.get_burn_amt(gogr_amt)?;
+        let left_amt = gogr_amt
+            .checked_sub(burn_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        let staking_amt = ctx.accounts.launchpad.add_staking_reward(left_amt)?;
+        ctx.accounts.transfer_gogr(
+            ctx.accounts.gogr_receiver_account.to_account_info(),
+            left_amt - staking_amt,
+        )?;
+        if staking_amt > 0 {
+            let stake_vault = ctx
+                .accounts
+                .stake_vault
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::StakeErr)?;
+            ctx.accounts
+                .transfer_gogr(stake_vault.to_account_info(), staking_amt)?;
+        }
+        ctx.accounts.burn_gogr(burn_amt)?;
+    }
+    Ok(())
+}
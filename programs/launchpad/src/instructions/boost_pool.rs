@@ -0,0 +1,120 @@
+use crate::constants::PAUSE_CREATE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::{add_pool_boost, GogrPayment};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BoostPoolArgs {
+    pub pool_id: u64,
+    // zero only refreshes the pool's place in featured_pools
+    pub gogr_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: BoostPoolArgs)]
+pub struct BoostPool<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [FEATURED_POOLS_SEED, launchpad.key().as_ref()],
+        bump,
+        space = 8 + FeaturedPools::INIT_SPACE
+    )]
+    pub featured_pools: Box<Account<'info, FeaturedPools>>,
+
+    #[account(mut, address = launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK:
+    #[account(address = launchpad.gogr_receiver)]
+    pub gogr_receiver: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = signer
+    )]
+    pub gogr_from_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = gogr_receiver
+    )]
+    pub gogr_receiver_account: Box<Account<'info, TokenAccount>>,
+
+    // required while gogr is staked, see Launchpad::add_staking_reward
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+pub fn boost_pool_handler(ctx: Context<BoostPool>, args: BoostPoolArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let clock = Clock::get()?;
+    let cur = clock.unix_timestamp;
+
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::Active,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+
+    let featured_pools = &mut ctx.accounts.featured_pools;
+    featured_pools.init(ctx.bumps.featured_pools, ctx.accounts.launchpad.key());
+    if args.gogr_amount == 0 {
+        featured_pools.update(pool.key(), pool, cur);
+        return Ok(());
+    }
+    let payment = GogrPayment {
+        signer: &ctx.accounts.signer,
+        gogr_mint: &ctx.accounts.gogr_mint,
+        gogr_from_account: &ctx.accounts.gogr_from_account,
+        gogr_receiver_account: &ctx.accounts.gogr_receiver_account,
+        stake_vault: ctx.accounts.stake_vault.as_deref(),
+        token_program: &ctx.accounts.token_program,
+    };
+    add_pool_boost(
+        pool,
+        featured_pools,
+        &mut ctx.accounts.launchpad,
+        &payment,
+        args.gogr_amount,
+        cur,
+    )
+}
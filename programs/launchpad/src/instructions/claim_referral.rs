@@ -0,0 +1,95 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::withdraw_pool_token;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClaimReferralArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClaimReferralArgs)]
+pub struct ClaimReferral<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, pool.key().as_ref(), signer.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Box<Account<'info, Referral>>,
+
+    #[account(address=pool.token_mint)]
+    pub pool_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = signer,
+        payer=signer
+    )]
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// referral earnings were already taken out of available_referral_amount on collect
+pub fn claim_referral_handler(ctx: Context<ClaimReferral>, _args: ClaimReferralArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let referral = &mut ctx.accounts.referral;
+    let amt = referral.available_amount;
+    require!(amt > 0, LaunchpadErrorCode::TokenAmtErr);
+    referral.available_amount = 0;
+    withdraw_pool_token(
+        &ctx.accounts.pool,
+        &ctx.accounts.pool_token_account,
+        &ctx.accounts.token_owner_account,
+        &ctx.accounts.sol_vault,
+        ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amt,
+    )
+}
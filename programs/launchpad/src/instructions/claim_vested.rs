@@ -0,0 +1,94 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::withdraw_pool_token;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClaimVestedArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClaimVestedArgs)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_SEED, pool.key().as_ref(), signer.key().as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    #[account(address=pool.token_mint)]
+    pub pool_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = signer,
+        payer=signer
+    )]
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// vested tokens were already taken out of available_token_amount on collect
+pub fn claim_vested_handler(ctx: Context<ClaimVested>, _args: ClaimVestedArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let clock = Clock::get()?;
+    let amt = ctx.accounts.vesting.claim(clock.unix_timestamp)?;
+    require!(amt > 0, LaunchpadErrorCode::TokenAmtErr);
+    withdraw_pool_token(
+        &ctx.accounts.pool,
+        &ctx.accounts.pool_token_account,
+        &ctx.accounts.token_owner_account,
+        &ctx.accounts.sol_vault,
+        ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.token_program,
+        &ctx.accounts.system_program,
+        amt,
+    )
+}
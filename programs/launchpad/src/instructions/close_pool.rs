@@ -0,0 +1,140 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::{transfer_pool_rewards, withdraw_pool_token};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ClosePoolArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClosePoolArgs)]
+pub struct ClosePool<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TOKEN_EXIST_SEED,
+            launchpad.key().as_ref(),
+            pool.token_mint.as_ref(),
+            pool.owner.as_ref()
+        ],
+        bump = token_exist.bump
+    )]
+    pub token_exist: Box<Account<'info, TokenExist>>,
+
+    #[account(address = pool.token_mint)]
+    pub pool_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = owner
+    )]
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_pool_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClosePool<'info>>,
+    _args: ClosePoolArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::Active,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    // players keep what they mined, so every game has to end first, abandoned
+    // ones through expire_game
+    require!(pool.active_games == 0, LaunchpadErrorCode::ActiveGamesErr);
+    pool.status = PoolStatus::Closed;
+
+    // refund what is left
+    let amt = pool
+        .available_token_amount
+        .checked_add(pool.available_bonus_amount)
+        .and_then(|v| v.checked_add(pool.available_referral_amount))
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    pool.available_token_amount = 0;
+    pool.available_bonus_amount = 0;
+    pool.available_referral_amount = 0;
+    let mut rewards = Vec::with_capacity(pool.extra_rewards.len() + pool.nft_mints.len());
+    for reward in pool.extra_rewards.iter_mut() {
+        rewards.push((reward.mint, reward.available_token_amount));
+        reward.available_token_amount = 0;
+    }
+    rewards.extend(pool.take_nfts().into_iter().map(|mint| (mint, 1)));
+
+    let token_exist = &mut ctx.accounts.token_exist;
+    if token_exist.active_pool == pool.key() {
+        token_exist.active_pool = Pubkey::default();
+    }
+
+    if amt > 0 {
+        withdraw_pool_token(
+            &ctx.accounts.pool,
+            &ctx.accounts.pool_token_account,
+            &ctx.accounts.token_owner_account,
+            &ctx.accounts.sol_vault,
+            ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.token_program,
+            &ctx.accounts.system_program,
+            amt,
+        )?;
+    }
+    transfer_pool_rewards(
+        &ctx.accounts.pool,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        ctx.accounts.owner.key(),
+        &rewards,
+    )
+}
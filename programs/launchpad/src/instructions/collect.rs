@@ -1,5 +1,7 @@
+use crate::constants::PAUSE_COLLECT;
 use crate::errors::LaunchpadErrorCode;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{transfer, Mint, Token, TokenAccount, Transfer},
@@ -7,6 +9,9 @@ use anchor_spl::{
 
 use crate::seeds::*;
 use crate::state::*;
+use crate::utils::{
+    check_top_level, record_tournament_entry, transfer_pool_rewards, withdraw_pool_token,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct CollectArgs {
@@ -19,13 +24,29 @@ pub struct Collect<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    #[account(mut,seeds = [POOL_INFO_SEED,args.pool_id.to_be_bytes().as_ref()],bump=pool.bump)]
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
     pub pool: Box<Account<'info, Pool>>,
 
     #[account(
         mut,
         seeds = [
             GAME_INFO_SEED,
+            launchpad.key().as_ref(),
             pool.pool_id.to_be_bytes().as_ref(),
             signer.key().as_ref()
         ],
@@ -41,41 +62,167 @@ pub struct Collect<'info> {
         associated_token::authority = signer,
         payer=signer
     )]
-    pub token_owner_account: Box<Account<'info, TokenAccount>>,
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
 
     #[account(
         mut,
         associated_token::mint = pool_token_mint,
         associated_token::authority = pool
     )]
-    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    // vesting pools only, see Pool::is_vesting
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [VESTING_SEED, pool.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + Vesting::INIT_SPACE
+    )]
+    pub vesting: Option<Box<Account<'info, Vesting>>>,
+
+    // tracks the daily streak and the global cooldown, a referrer gets the pool referral share.
+    // required in CooldownScope::Global pools and pools with a referral share
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [USER_PROFILE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub user_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [
+            REFERRAL_SEED,
+            pool.key().as_ref(),
+            user_profile.as_ref().map(|p| p.referrer).unwrap_or_default().as_ref()
+        ],
+        bump,
+        space = 8 + Referral::INIT_SPACE
+    )]
+    pub referral: Option<Box<Account<'info, Referral>>>,
+
+    // every collect is recorded, so the leaderboard can't be skipped
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [LEADERBOARD_SEED, pool.key().as_ref()],
+        bump,
+        space = 8 + Leaderboard::INIT_SPACE
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+
+    // stakers only, shortens the cooldown by the launchpad stake tier
+    #[account(
+        seeds = [USER_STAKE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump = user_stake.bump
+    )]
+    pub user_stake: Option<Box<Account<'info, UserStake>>>,
+
+    // required while pool.tournament runs, see utils::record_tournament_entry
+    #[account(mut, address = pool.tournament @ LaunchpadErrorCode::TournamentErr)]
+    pub tournament: Option<Box<Account<'info, Tournament>>>,
+
+    /// CHECK: empty when the signer never entered, loaded in utils::record_tournament_entry
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_ENTRY_SEED, pool.tournament.as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub tournament_entry: Option<UncheckedAccount<'info>>,
+
+    // required while launchpad.collect_fee is set
+    #[account(
+        mut,
+        token::mint = launchpad.gogr_mint,
+        token::authority = signer
+    )]
+    pub gogr_from_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = launchpad.gogr_mint,
+        token::authority = launchpad.gogr_receiver
+    )]
+    pub gogr_receiver_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: see utils::check_top_level
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 impl<'info> Collect<'info> {
-    fn transfer_pool_token(&self, amount: u64) -> Result<()> {
-        let pool_id_bytes = self.pool.pool_id.to_be_bytes();
-        let signer_seeds = [POOL_INFO_SEED, pool_id_bytes.as_ref(), &[self.pool.bump]];
+    fn transfer_gogr(
+        &self,
+        to: &Option<Box<Account<'info, TokenAccount>>>,
+        amount: u64,
+    ) -> Result<()> {
+        let (Some(to), Some(gogr_from_account)) = (to, &self.gogr_from_account) else {
+            return err!(LaunchpadErrorCode::GogrFeeErr);
+        };
         transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
                 Transfer {
-                    to: self.token_owner_account.to_account_info(),
-                    from: self.pool_token_account.to_account_info(),
-                    authority: self.pool.to_account_info(),
+                    to: to.to_account_info(),
+                    from: gogr_from_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
                 },
-            )
-            .with_signer(&[&signer_seeds]),
+            ),
             amount,
         )
     }
+
+    // the stakers' share goes to the stake vault, the rest to the receiver
+    fn charge_collect_fee(&mut self) -> Result<()> {
+        let fee_amt = self.launchpad.collect_fee;
+        if fee_amt == 0 {
+            return Ok(());
+        }
+        let staking_amt = self.launchpad.add_staking_reward(fee_amt)?;
+        self.transfer_gogr(&self.gogr_receiver_account, fee_amt - staking_amt)?;
+        if staking_amt > 0 {
+            self.transfer_gogr(&self.stake_vault, staking_amt)?;
+        }
+        Ok(())
+    }
 }
 
-pub fn collect_handler(ctx: Context<Collect>, _args: CollectArgs) -> Result<()> {
+pub fn collect_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Collect<'info>>,
+    _args: CollectArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    check_top_level(&ctx.accounts.instructions_sysvar)?;
+    ctx.accounts.charge_collect_fee()?;
+
     let pool = &mut ctx.accounts.pool;
-    pool.game_count += 1;
+    pool.game_count = pool
+        .game_count
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    pool.end_game();
 
     let game_info = &mut ctx.accounts.game_info;
     require!(
@@ -84,26 +231,180 @@ pub fn collect_handler(ctx: Context<Collect>, _args: CollectArgs) -> Result<()>
     );
 
     let token_amount = game_info.token_amount;
+    let bonus_amount = game_info.bonus_amount;
+    let step = game_info.step;
+    let extra_token_amounts = game_info.extra_token_amounts.clone();
+    let nft_rewards = std::mem::take(&mut game_info.nft_rewards);
 
     game_info.game_status = GameStatus::Collected;
-    require!(token_amount > 0, LaunchpadErrorCode::TokenAmtErr);
-    game_info.game_counter += 1;
+    require!(
+        token_amount > 0
+            || bonus_amount > 0
+            || extra_token_amounts.iter().any(|amt| *amt > 0)
+            || !nft_rewards.is_empty(),
+        LaunchpadErrorCode::TokenAmtErr
+    );
+    game_info.game_counter = game_info
+        .game_counter
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
 
     // check cooldown
     let clock = Clock::get()?;
     let cur = clock.unix_timestamp;
-    game_info.cooldown_timestamp = cur as u32 + (pool.cooldown_duration as u32) * 60;
+    game_info.cooldown_reduction_bps = ctx.accounts.user_stake.as_ref().map_or(0, |s| {
+        ctx.accounts.launchpad.get_cooldown_reduction_bps(s.amount)
+    });
+    game_info.set_cooldown(pool, cur)?;
+
+    require!(
+        pool.cooldown_scope == CooldownScope::Pool || ctx.accounts.user_profile.is_some(),
+        LaunchpadErrorCode::CooldownErr
+    );
+    // the profile names the referrer, so players can't skip the referral share
+    require!(
+        pool.referral_bps == 0 || ctx.accounts.user_profile.is_some(),
+        LaunchpadErrorCode::ReferrerErr
+    );
+    let mut daily_bonus_amt = 0;
+    if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+        user_profile.init(
+            ctx.bumps.user_profile,
+            ctx.accounts.launchpad.key(),
+            ctx.accounts.signer.key(),
+            cur,
+        );
+        if pool.cooldown_scope == CooldownScope::Global {
+            user_profile.end_game(game_info.cooldown_timestamp);
+        }
+        if user_profile.record_day(cur) {
+            daily_bonus_amt = pool.get_daily_bonus_amt(token_amount, user_profile.streak_days)?;
+        }
+    }
 
-    let mut amt = token_amount;
-    if amt > pool.available_token_amount {
-        amt = pool.available_token_amount
+    // the daily bonus gets what the game leaves in the pool, and only the paid
+    // part counts against today's budget
+    let token_amt = token_amount.min(pool.available_token_amount);
+    if daily_bonus_amt > 0 {
+        let left_amt = pool.available_token_amount - token_amt;
+        daily_bonus_amt = pool.take_daily_bonus(daily_bonus_amt.min(left_amt), cur)?;
+    }
+    let amt = token_amt
+        .checked_add(daily_bonus_amt)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    let mut bonus_amt = bonus_amount;
+    if bonus_amt > pool.available_bonus_amount {
+        bonus_amt = pool.available_bonus_amount
     }
 
-    if amt > 0 {
+    let payout_amt = amt
+        .checked_add(bonus_amt)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    if payout_amt > 0 {
         // transfer pool token
-        pool.available_token_amount -= amt;
-        ctx.accounts.transfer_pool_token(amt)?;
+        pool.available_token_amount = pool
+            .available_token_amount
+            .checked_sub(amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_bonus_amount = pool
+            .available_bonus_amount
+            .checked_sub(bonus_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        if pool.is_vesting() {
+            // tokens stay in the pool until claim_vested
+            let vesting = ctx
+                .accounts
+                .vesting
+                .as_mut()
+                .ok_or(LaunchpadErrorCode::VestingErr)?;
+            if vesting.pool == Pubkey::default() {
+                vesting.bump = ctx.bumps.vesting;
+                vesting.pool = pool.key();
+                vesting.user = ctx.accounts.signer.key();
+            }
+            vesting.add_amount(pool, payout_amt, cur)?;
+        } else {
+            withdraw_pool_token(
+                &ctx.accounts.pool,
+                &ctx.accounts.pool_token_account,
+                &ctx.accounts.token_owner_account,
+                &ctx.accounts.sol_vault,
+                ctx.accounts.signer.to_account_info(),
+                &ctx.accounts.token_program,
+                &ctx.accounts.system_program,
+                payout_amt,
+            )?;
+        }
+    }
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    if leaderboard.pool == Pubkey::default() {
+        leaderboard.bump = ctx.bumps.leaderboard;
+        leaderboard.pool = ctx.accounts.pool.key();
+    }
+    leaderboard.update(ctx.accounts.signer.key(), payout_amt, step)?;
+    record_tournament_entry(
+        &ctx.accounts.pool,
+        &mut ctx.accounts.tournament,
+        &ctx.accounts.tournament_entry,
+        cur,
+        |entry| entry.record_collect(payout_amt, step),
+    )?;
+
+    // the referral share comes from the referral sub-pool, not from the player,
+    // and is taken on the game's tokens without the daily bonus
+    let pool = &mut ctx.accounts.pool;
+    let referrer = ctx
+        .accounts
+        .user_profile
+        .as_ref()
+        .map(|p| p.referrer)
+        .unwrap_or_default();
+    let referral_amt = if referrer != Pubkey::default() {
+        pool.get_referral_amt(token_amt)?
+    } else {
+        0
+    };
+    if referral_amt > 0 {
+        let referral = ctx
+            .accounts
+            .referral
+            .as_mut()
+            .ok_or(LaunchpadErrorCode::ReferrerErr)?;
+        if referral.pool == Pubkey::default() {
+            referral.bump = ctx.bumps.referral;
+            referral.pool = pool.key();
+            referral.referrer = referrer;
+        }
+        pool.available_referral_amount = pool
+            .available_referral_amount
+            .checked_sub(referral_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        referral.total_amount = referral
+            .total_amount
+            .checked_add(referral_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        referral.available_amount = referral
+            .available_amount
+            .checked_add(referral_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    }
+
+    let mut rewards = Vec::with_capacity(extra_token_amounts.len() + nft_rewards.len());
+    for (reward, amount) in pool.extra_rewards.iter_mut().zip(extra_token_amounts) {
+        let amt = amount.min(reward.available_token_amount);
+        reward.available_token_amount -= amt;
+        rewards.push((reward.mint, amt));
     }
+    pool.release_nfts(nft_rewards.len());
+    rewards.extend(nft_rewards.into_iter().map(|mint| (mint, 1)));
+    transfer_pool_rewards(
+        &ctx.accounts.pool,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        ctx.accounts.signer.key(),
+        &rewards,
+    )?;
 
     Ok(())
 }
@@ -2,12 +2,13 @@ use crate::errors::LaunchpadErrorCode;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
 
-use crate::constants::ALLOCATION_PERCENTAGE;
+use crate::constants::{ALLOCATION_PERCENTAGE, MAX_DAILY_BONUS_BPS, PAUSE_CREATE};
 use crate::seeds::*;
 use crate::state::*;
+use crate::utils::{add_pool_boost, deposit_sol, GogrPayment};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreatePoolArgs {
@@ -15,11 +16,34 @@ pub struct CreatePoolArgs {
     pub web_site: String,
     pub gold_grid: u8,
     pub bomb_grid: u8,
-    pub cooldown_duration: u16,
+    pub cooldown_duration: u32,
     pub allocation_value: u64,
     pub allocation_percentage: u64,
     pub gogr_ext: u64,
     pub token_amount: u64,
+    pub adjacency_rule: AdjacencyRule,
+    pub start_cell: StartCell,
+    pub gem_grid: u8,
+    pub gem_multiplier: u8,
+    pub chest_grid: u8,
+    pub cave_in_grid: u8,
+    pub dud_grid: u8,
+    pub dud_cooldown: u32,
+    pub bonus_token_amount: u64,
+    pub nft_grid: u8,
+    pub nft_collection: Pubkey,
+    pub gate_collection: Pubkey,
+    pub vesting_cliff: u32,
+    pub vesting_duration: u32,
+    pub daily_bonus_bps: u16,
+    pub streak_bonus_bps: u16,
+    pub daily_bonus_budget: u64,
+    pub cooldown_scope: CooldownScope,
+    pub attestation_signer: Pubkey,
+    pub min_gogr_balance: u64,
+    pub min_gogr_staked: u64,
+    pub min_token_balance: u64,
+    pub min_profile_age: u32,
 }
 
 #[derive(Accounts)]
@@ -28,7 +52,11 @@ pub struct CreatePool<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    #[account(mut,seeds = [LAUNCHPAD_CONFIG_SEED],bump=launchpad.bump)]
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
     pub launchpad: Box<Account<'info, Launchpad>>,
 
     #[account(
@@ -36,6 +64,7 @@ pub struct CreatePool<'info> {
         payer = signer,
         seeds = [
             POOL_INFO_SEED,
+            launchpad.key().as_ref(),
             launchpad.last_pool_id.to_be_bytes().as_ref()
             ],
         bump,
@@ -65,27 +94,59 @@ pub struct CreatePool<'info> {
     )]
     pub gogr_receiver_account: Box<Account<'info, TokenAccount>>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [FEATURED_POOLS_SEED, launchpad.key().as_ref()],
+        bump,
+        space = 8 + FeaturedPools::INIT_SPACE
+    )]
+    pub featured_pools: Box<Account<'info, FeaturedPools>>,
+
+    // gogr escrow, required when the launchpad reviews new pools
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_gogr_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // required while gogr is staked, see Launchpad::add_staking_reward
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub pool_token_mint: Box<Account<'info, Mint>>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = signer,
         seeds = [
             TOKEN_EXIST_SEED,
-            pool_token_mint.key().as_ref()
+            launchpad.key().as_ref(),
+            pool_token_mint.key().as_ref(),
+            signer.key().as_ref()
         ],
         bump,
         space = 8 + TokenExist::INIT_SPACE)
     ]
     pub token_exist: Box<Account<'info, TokenExist>>,
 
+    // the mint's current pool, required while it is still running
+    #[account(address = token_exist.active_pool @ LaunchpadErrorCode::TokenExistErr)]
+    pub previous_pool: Option<Box<Account<'info, Pool>>>,
+
     #[account(
         mut,
         associated_token::mint = pool_token_mint,
         associated_token::authority = signer
     )]
-    pub token_owner_account: Box<Account<'info, TokenAccount>>,
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
 
     #[account(
         init_if_needed,
@@ -93,33 +154,31 @@ pub struct CreatePool<'info> {
         associated_token::mint = pool_token_mint,
         associated_token::authority = pool
     )]
-    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 impl<'info> CreatePool<'info> {
-    fn transfer_gogr(&self, amount: u64) -> Result<()> {
+    fn escrow_gogr(&self, amount: u64) -> Result<()> {
+        let pool_gogr_account = self
+            .pool_gogr_account
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::ParamErr)?;
         transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
                 Transfer {
-                    to: self.gogr_receiver_account.to_account_info(),
-                    from: self.gogr_from_account.to_account_info(),
-                    authority: self.signer.to_account_info(),
-                },
-            ),
-            amount,
-        )
-    }
-
-    fn burn_gogr(&self, amount: u64) -> Result<()> {
-        burn(
-            CpiContext::new(
-                self.token_program.to_account_info(),
-                Burn {
-                    mint: self.gogr_mint.to_account_info(),
+                    to: pool_gogr_account.to_account_info(),
                     from: self.gogr_from_account.to_account_info(),
                     authority: self.signer.to_account_info(),
                 },
@@ -129,12 +188,24 @@ impl<'info> CreatePool<'info> {
     }
 
     fn transfer_pool_token(&self, amount: u64) -> Result<()> {
+        if self.pool.is_sol() {
+            let sol_vault = self
+                .sol_vault
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+            return deposit_sol(&self.signer, sol_vault, &self.system_program, amount);
+        }
+        let (Some(pool_token_account), Some(token_owner_account)) =
+            (&self.pool_token_account, &self.token_owner_account)
+        else {
+            return err!(LaunchpadErrorCode::RewardAccountErr);
+        };
         transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
                 Transfer {
-                    to: self.pool_token_account.to_account_info(),
-                    from: self.token_owner_account.to_account_info(),
+                    to: pool_token_account.to_account_info(),
+                    from: token_owner_account.to_account_info(),
                     authority: self.signer.to_account_info(),
                 },
             ),
@@ -144,11 +215,39 @@ impl<'info> CreatePool<'info> {
 }
 
 pub fn create_pool_handler(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
     let launchpad = &mut ctx.accounts.launchpad;
     let pool = &mut ctx.accounts.pool;
 
     pool.bump = ctx.bumps.pool;
+    pool.sol_vault_bump = ctx.bumps.sol_vault;
     pool.pool_id = launchpad.last_pool_id;
+    pool.launchpad = launchpad.key();
+
+    // one running pool per mint and creator
+    let token_exist = &mut ctx.accounts.token_exist;
+    if token_exist.season_count == 0 {
+        token_exist.bump = ctx.bumps.token_exist;
+        token_exist.mint = ctx.accounts.pool_token_mint.key();
+        token_exist.creator = ctx.accounts.signer.key();
+    } else if token_exist.active_pool != Pubkey::default() {
+        let previous_pool = ctx
+            .accounts
+            .previous_pool
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::TokenExistErr)?;
+        require!(
+            previous_pool.is_finished(),
+            LaunchpadErrorCode::TokenExistErr
+        );
+    }
+    token_exist.push_pool(pool.key(), pool.pool_id)?;
+    pool.season = token_exist.season_count;
+    pool.status = if launchpad.approval_required {
+        PoolStatus::PendingReview
+    } else {
+        PoolStatus::Active
+    };
     pool.token_mint = ctx.accounts.pool_token_mint.key();
     pool.owner = ctx.accounts.signer.key();
     pool.icon_url = args.icon_url;
@@ -156,42 +255,147 @@ pub fn create_pool_handler(ctx: Context<CreatePool>, args: CreatePoolArgs) -> Re
     pool.gold_grid = args.gold_grid;
     pool.bomb_grid = args.bomb_grid;
     pool.cooldown_duration = args.cooldown_duration;
+    pool.cooldown_scope = args.cooldown_scope;
+    pool.attestation_signer = args.attestation_signer;
+    pool.min_gogr_balance = args.min_gogr_balance;
+    pool.min_gogr_staked = args.min_gogr_staked;
+    pool.min_token_balance = args.min_token_balance;
+    pool.min_profile_age = args.min_profile_age;
     pool.allocation_value = args.allocation_value;
     require!(
         args.allocation_percentage < ALLOCATION_PERCENTAGE,
         LaunchpadErrorCode::ParamErr
     );
     pool.allocation_percentage = args.allocation_percentage;
+    require!(args.start_cell.is_valid(), LaunchpadErrorCode::ParamErr);
+    pool.adjacency_rule = args.adjacency_rule;
+    pool.start_cell = args.start_cell;
 
-    let total_grid = args.gold_grid.checked_add(args.bomb_grid).unwrap();
+    pool.gem_grid = args.gem_grid;
+    pool.gem_multiplier = args.gem_multiplier;
+    pool.chest_grid = args.chest_grid;
+    pool.cave_in_grid = args.cave_in_grid;
+    pool.dud_grid = args.dud_grid;
+    pool.dud_cooldown = args.dud_cooldown;
+    pool.nft_grid = args.nft_grid;
+    pool.nft_collection = args.nft_collection;
+    pool.gate_collection = args.gate_collection;
     require!(
-        total_grid < pool.total_grid() && total_grid > 0,
+        args.vesting_cliff <= args.vesting_duration,
+        LaunchpadErrorCode::VestingErr
+    );
+    pool.vesting_cliff = args.vesting_cliff;
+    pool.vesting_duration = args.vesting_duration;
+    require!(
+        args.daily_bonus_bps <= MAX_DAILY_BONUS_BPS && args.streak_bonus_bps <= MAX_DAILY_BONUS_BPS,
+        LaunchpadErrorCode::DailyBonusRateErr
+    );
+    pool.daily_bonus_bps = args.daily_bonus_bps;
+    pool.streak_bonus_bps = args.streak_bonus_bps;
+    pool.daily_bonus_budget = args.daily_bonus_budget;
+    require!(
+        args.gem_grid == 0 || args.gem_multiplier > 0,
+        LaunchpadErrorCode::ParamErr
+    );
+    require!(
+        args.chest_grid == 0 || args.bonus_token_amount > 0,
+        LaunchpadErrorCode::ParamErr
+    );
+
+    let total_grid = args.gold_grid as u16
+        + args.bomb_grid as u16
+        + args.gem_grid as u16
+        + args.chest_grid as u16
+        + args.cave_in_grid as u16
+        + args.dud_grid as u16
+        + args.nft_grid as u16;
+    require!(
+        total_grid < pool.total_grid() as u16 && total_grid > 0,
         LaunchpadErrorCode::GameGridErr
     );
 
-    launchpad.last_pool_id = launchpad.last_pool_id.checked_add(1).unwrap();
+    launchpad.last_pool_id = launchpad
+        .last_pool_id
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
 
     require!(args.token_amount > 0, LaunchpadErrorCode::TokenAmtErr);
 
     if args.token_amount > 0 {
-        pool.token_amount += args.token_amount;
-        pool.available_token_amount += args.token_amount;
+        pool.token_amount = pool
+            .token_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_token_amount = pool
+            .available_token_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    }
+    if args.bonus_token_amount > 0 {
+        pool.bonus_token_amount = pool
+            .bonus_token_amount
+            .checked_add(args.bonus_token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_bonus_amount = pool
+            .available_bonus_amount
+            .checked_add(args.bonus_token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
     }
     // transfer gogr
     if launchpad.gogr_mint != pool.token_mint {
-        let gogr_amt = args.gogr_ext.checked_add(launchpad.gogr_fee).unwrap();
-        if gogr_amt > 0 {
-            pool.gogr_ext += gogr_amt;
-            let burn_amt = gogr_amt * launchpad.burn_rate as u64 / 100 as u64;
-            let left_amt = gogr_amt - burn_amt;
-            ctx.accounts.transfer_gogr(left_amt)?;
-            ctx.accounts.burn_gogr(burn_amt)?;
+        let gogr_amt = args
+            .gogr_ext
+            .checked_add(launchpad.gogr_fee)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        if launchpad.approval_required && gogr_amt > 0 {
+            // burn, fee and boost are settled by approve_pool / reject_pool
+            pool.gogr_ext = pool
+                .gogr_ext
+                .checked_add(gogr_amt)
+                .ok_or(LaunchpadErrorCode::MathOverflow)?;
+            pool.gogr_escrow = gogr_amt;
+            pool.boost_escrow = args.gogr_ext;
+            ctx.accounts.escrow_gogr(gogr_amt)?;
+        } else if gogr_amt > 0 {
+            let payment = GogrPayment {
+                signer: &ctx.accounts.signer,
+                gogr_mint: &ctx.accounts.gogr_mint,
+                gogr_from_account: &ctx.accounts.gogr_from_account,
+                gogr_receiver_account: &ctx.accounts.gogr_receiver_account,
+                stake_vault: ctx.accounts.stake_vault.as_deref(),
+                token_program: &ctx.accounts.token_program,
+            };
+            let gogr_fee = launchpad.gogr_fee;
+            if gogr_fee > 0 {
+                pool.gogr_ext = pool
+                    .gogr_ext
+                    .checked_add(gogr_fee)
+                    .ok_or(LaunchpadErrorCode::MathOverflow)?;
+                payment.pay(launchpad, gogr_fee)?;
+            }
+            if args.gogr_ext > 0 {
+                let clock = Clock::get()?;
+                let featured_pools = &mut ctx.accounts.featured_pools;
+                featured_pools.init(ctx.bumps.featured_pools, launchpad.key());
+                add_pool_boost(
+                    pool,
+                    featured_pools,
+                    launchpad,
+                    &payment,
+                    args.gogr_ext,
+                    clock.unix_timestamp,
+                )?;
+            }
         }
     }
 
     // transfer pool token
-    if args.token_amount > 0 {
-        ctx.accounts.transfer_pool_token(args.token_amount)?;
+    let deposit_amt = args
+        .token_amount
+        .checked_add(args.bonus_token_amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    if deposit_amt > 0 {
+        ctx.accounts.transfer_pool_token(deposit_amt)?;
     }
     Ok(())
 }
@@ -0,0 +1,175 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_TOURNAMENT_POOLS, MAX_TOURNAMENT_WINNERS, PAUSE_CREATE,
+};
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateTournamentArgs {
+    pub pools: Vec<Pubkey>,
+    pub start_ts: i64,
+    pub entry_end_ts: i64,
+    pub end_ts: i64,
+    pub scoring: TournamentScoring,
+    pub prize_amount: u64,
+    pub prize_shares: Vec<u16>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTournament<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            TOURNAMENT_SEED,
+            launchpad.key().as_ref(),
+            launchpad.last_tournament_id.to_be_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + Tournament::INIT_SPACE
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+
+    pub prize_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = prize_mint,
+        associated_token::authority = signer
+    )]
+    pub signer_prize_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = prize_mint,
+        associated_token::authority = tournament
+    )]
+    pub tournament_prize_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> CreateTournament<'info> {
+    fn transfer_prize(&self, amount: u64) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to: self.tournament_prize_account.to_account_info(),
+                    from: self.signer_prize_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+// remaining accounts are the writable pools of args.pools, in order. Only the pool
+// owner can put a pool in a tournament. All pools pay the same token_mint so
+// scores add up one token, and a pool runs one tournament at a time.
+pub fn create_tournament_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateTournament<'info>>,
+    args: CreateTournamentArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let clock = Clock::get()?;
+    require!(
+        args.start_ts <= args.entry_end_ts
+            && args.entry_end_ts <= args.end_ts
+            && args.end_ts > clock.unix_timestamp,
+        LaunchpadErrorCode::TournamentTimeErr
+    );
+    require!(
+        !args.pools.is_empty() && args.pools.len() <= MAX_TOURNAMENT_POOLS,
+        LaunchpadErrorCode::TournamentErr
+    );
+    require!(
+        !args.prize_shares.is_empty() && args.prize_shares.len() <= MAX_TOURNAMENT_WINNERS,
+        LaunchpadErrorCode::TournamentErr
+    );
+    let total_share: u64 = args.prize_shares.iter().map(|s| *s as u64).sum();
+    require!(
+        total_share <= BPS_DENOMINATOR,
+        LaunchpadErrorCode::TournamentErr
+    );
+    require!(args.prize_amount > 0, LaunchpadErrorCode::TokenAmtErr);
+    require!(
+        ctx.remaining_accounts.len() == args.pools.len(),
+        LaunchpadErrorCode::TournamentErr
+    );
+
+    let tournament_key = ctx.accounts.tournament.key();
+    let launchpad_key = ctx.accounts.launchpad.key();
+    let signer = ctx.accounts.signer.key();
+    let mut token_mint = None;
+    for (pool_info, pool_key) in ctx.remaining_accounts.iter().zip(args.pools.iter()) {
+        require_keys_eq!(
+            pool_info.key(),
+            *pool_key,
+            LaunchpadErrorCode::TournamentErr
+        );
+        require_keys_eq!(
+            *pool_info.owner,
+            crate::ID,
+            LaunchpadErrorCode::TournamentErr
+        );
+        let mut pool = Pool::try_deserialize(&mut &pool_info.data.borrow()[..])?;
+        require!(
+            pool.launchpad == launchpad_key && pool.owner == signer,
+            LaunchpadErrorCode::TournamentErr
+        );
+        require!(
+            *token_mint.get_or_insert(pool.token_mint) == pool.token_mint,
+            LaunchpadErrorCode::TournamentErr
+        );
+        require!(
+            pool.tournament_end_ts <= clock.unix_timestamp,
+            LaunchpadErrorCode::TournamentErr
+        );
+        pool.tournament = tournament_key;
+        pool.tournament_end_ts = args.end_ts;
+        pool.try_serialize(&mut &mut pool_info.data.borrow_mut()[..])?;
+    }
+
+    let launchpad = &mut ctx.accounts.launchpad;
+    let tournament = &mut ctx.accounts.tournament;
+    tournament.bump = ctx.bumps.tournament;
+    tournament.tournament_id = launchpad.last_tournament_id;
+    tournament.launchpad = launchpad.key();
+    tournament.creator = ctx.accounts.signer.key();
+    tournament.pools = args.pools;
+    tournament.start_ts = args.start_ts;
+    tournament.entry_end_ts = args.entry_end_ts;
+    tournament.end_ts = args.end_ts;
+    tournament.scoring = args.scoring;
+    tournament.prize_mint = ctx.accounts.prize_mint.key();
+    tournament.prize_amount = args.prize_amount;
+    tournament.prize_shares = args.prize_shares;
+
+    launchpad.last_tournament_id = launchpad
+        .last_tournament_id
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    ctx.accounts.transfer_prize(args.prize_amount)
+}
@@ -0,0 +1,117 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    metadata::MetadataAccount,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::constants::{MAX_POOL_NFTS, PAUSE_CREATE};
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::check_collection;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DepositNftArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: DepositNftArgs)]
+pub struct DepositNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        constraint = nft_mint.decimals == 0 && nft_mint.supply == 1 @ LaunchpadErrorCode::NftErr
+    )]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    // required when the pool restricts deposits to pool.nft_collection
+    pub nft_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_nft_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_nft_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> DepositNft<'info> {
+    fn transfer_nft(&self) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to: self.pool_nft_account.to_account_info(),
+                    from: self.owner_nft_account.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            1,
+        )
+    }
+}
+
+pub fn deposit_nft_handler(ctx: Context<DepositNft>, _args: DepositNftArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let pool = &ctx.accounts.pool;
+    let nft_mint = ctx.accounts.nft_mint.key();
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    require!(pool.nft_grid > 0, LaunchpadErrorCode::NftErr);
+    require!(
+        nft_mint != pool.token_mint && pool.extra_rewards.iter().all(|r| r.mint != nft_mint),
+        LaunchpadErrorCode::RewardMintErr
+    );
+    require!(
+        (pool.nft_escrow_count as usize) < MAX_POOL_NFTS,
+        LaunchpadErrorCode::NftErr
+    );
+    if pool.nft_collection != Pubkey::default() {
+        let nft_metadata = ctx
+            .accounts
+            .nft_metadata
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::NftCollectionErr)?;
+        check_collection(nft_metadata, nft_mint, pool.nft_collection)?;
+    }
+
+    ctx.accounts.transfer_nft()?;
+    let pool = &mut ctx.accounts.pool;
+    pool.nft_mints.push(nft_mint);
+    pool.nft_escrow_count += 1;
+    Ok(())
+}
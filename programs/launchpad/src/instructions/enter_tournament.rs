@@ -0,0 +1,73 @@
+use crate::constants::PAUSE_MINE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct EnterTournamentArgs {
+    pub tournament_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: EnterTournamentArgs)]
+pub struct EnterTournament<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            launchpad.key().as_ref(),
+            args.tournament_id.to_be_bytes().as_ref()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            TOURNAMENT_ENTRY_SEED,
+            tournament.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump,
+        space = 8 + TournamentEntry::INIT_SPACE
+    )]
+    pub tournament_entry: Box<Account<'info, TournamentEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn enter_tournament_handler(
+    ctx: Context<EnterTournament>,
+    _args: EnterTournamentArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_MINE)?;
+    let tournament = &mut ctx.accounts.tournament;
+    let clock = Clock::get()?;
+    require!(
+        !tournament.settled && clock.unix_timestamp < tournament.entry_end_ts,
+        LaunchpadErrorCode::TournamentTimeErr
+    );
+    tournament.entry_count = tournament
+        .entry_count
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    let tournament_entry = &mut ctx.accounts.tournament_entry;
+    tournament_entry.bump = ctx.bumps.tournament_entry;
+    tournament_entry.tournament = tournament.key();
+    tournament_entry.user = ctx.accounts.signer.key();
+    Ok(())
+}
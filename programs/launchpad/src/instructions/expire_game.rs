@@ -0,0 +1,98 @@
+use crate::constants::PAUSE_MINE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ExpireGameArgs {
+    pub pool_id: u64,
+    pub user: Pubkey,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ExpireGameArgs)]
+pub struct ExpireGame<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_INFO_SEED,
+            launchpad.key().as_ref(),
+            pool.pool_id.to_be_bytes().as_ref(),
+            args.user.as_ref()
+        ],
+        bump = game_info.bump
+    )]
+    pub game_info: Box<Account<'info, GameInfo>>,
+
+    // required in CooldownScope::Global pools, releases the wallet's running game
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, launchpad.key().as_ref(), args.user.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Box<Account<'info, UserProfile>>>,
+}
+
+// Ends a running game without paying it, like an explosion. The player can
+// forfeit at any time, anyone else once the game sat GAME_TIMEOUT seconds.
+pub fn expire_game_handler(ctx: Context<ExpireGame>, args: ExpireGameArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_MINE)?;
+    let clock = Clock::get()?;
+    let cur = clock.unix_timestamp;
+
+    let game_info = &mut ctx.accounts.game_info;
+    require!(
+        game_info.game_status == GameStatus::Working,
+        LaunchpadErrorCode::GameStatusErr
+    );
+    require!(
+        ctx.accounts.signer.key() == args.user || game_info.is_expired(cur),
+        LaunchpadErrorCode::GameExpiryErr
+    );
+    game_info.game_status = GameStatus::Expired;
+    game_info.set_cooldown(&ctx.accounts.pool, cur)?;
+    game_info.game_counter = game_info
+        .game_counter
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    let pool = &mut ctx.accounts.pool;
+    if pool.cooldown_scope == CooldownScope::Global {
+        let user_profile = ctx
+            .accounts
+            .user_profile
+            .as_mut()
+            .ok_or(LaunchpadErrorCode::CooldownErr)?;
+        user_profile.end_game(game_info.cooldown_timestamp);
+    }
+
+    // reserved nfts go back to the pool
+    pool.nft_mints.append(&mut game_info.nft_rewards);
+    pool.end_game();
+    pool.game_count = pool
+        .game_count
+        .checked_add(1)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    Ok(())
+}
@@ -1,33 +1,66 @@
+use crate::errors::LaunchpadErrorCode;
 use anchor_lang::prelude::*;
 
+use crate::program::Launchpad as LaunchpadProgram;
 use crate::seeds::*;
 use crate::state::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct InitLaunchpadArgs {
+    pub launchpad_id: u64,
     pub gogr_mint: Pubkey,
     pub gogr_fee: u64,
     pub gogr_receiver: Pubkey,
     pub manager: Pubkey,
     pub burn_rate: u8,
+    pub approval_required: bool,
+    pub reject_refund_rate: u8,
+    pub skip_cooldown_rate: u64,
+    pub stake_tiers: Vec<StakeTier>,
+    pub staking_fee_rate: u8,
+    pub unbonding_period: u32,
+    pub collect_fee: u64,
 }
 
 #[derive(Accounts)]
+#[instruction(args: InitLaunchpadArgs)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
-    #[account(init, payer = signer, seeds = [LAUNCHPAD_CONFIG_SEED],bump, space = 8 + Launchpad::INIT_SPACE)]
+    #[account(
+        init,
+        payer = signer,
+        seeds = [LAUNCHPAD_CONFIG_SEED, args.launchpad_id.to_be_bytes().as_ref()],
+        bump,
+        space = 8 + Launchpad::INIT_SPACE
+    )]
     pub launchpad: Box<Account<'info, Launchpad>>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, LaunchpadProgram>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key())
+            @ LaunchpadErrorCode::UpgradeAuthorityErr
+    )]
+    pub program_data: Box<Account<'info, ProgramData>>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn initialize_handler(ctx: Context<Initialize>, args: InitLaunchpadArgs) -> Result<()> {
     let launchpad = &mut ctx.accounts.launchpad;
     launchpad.bump = ctx.bumps.launchpad;
+    launchpad.launchpad_id = args.launchpad_id;
     launchpad.gogr_mint = args.gogr_mint;
     launchpad.gogr_fee = args.gogr_fee;
     launchpad.gogr_receiver = args.gogr_receiver;
     launchpad.manager = args.manager;
     launchpad.burn_rate = args.burn_rate;
-    Ok(())
+    launchpad.approval_required = args.approval_required;
+    launchpad.reject_refund_rate = args.reject_refund_rate;
+    launchpad.skip_cooldown_rate = args.skip_cooldown_rate;
+    launchpad.stake_tiers = args.stake_tiers;
+    launchpad.staking_fee_rate = args.staking_fee_rate;
+    launchpad.unbonding_period = args.unbonding_period;
+    launchpad.collect_fee = args.collect_fee;
+    launchpad.deployer = ctx.accounts.signer.key();
+    launchpad.validate()
 }
@@ -1,7 +1,11 @@
+use crate::constants::PAUSE_MINE;
 use crate::errors::LaunchpadErrorCode;
 use crate::seeds::*;
 use crate::state::*;
+use crate::utils::{check_attestation, check_collection, check_top_level, record_tournament_entry};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::{metadata::MetadataAccount, token::TokenAccount};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct MineArgs {
@@ -17,10 +21,17 @@ pub struct Mine<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
     #[account(
         mut,
         seeds = [
             POOL_INFO_SEED,
+            launchpad.key().as_ref(),
             args.pool_id.to_be_bytes().as_ref()
             ],
         bump=pool.bump
@@ -32,6 +43,7 @@ pub struct Mine<'info> {
         payer = signer,
         seeds = [
             GAME_INFO_SEED,
+            launchpad.key().as_ref(),
             args.pool_id.to_be_bytes().as_ref(),
             signer.key().as_ref()
             ],
@@ -39,13 +51,145 @@ pub struct Mine<'info> {
         space = 8 + GameInfo::INIT_SPACE)]
     pub game_info: Box<Account<'info, GameInfo>>,
 
+    // gated pools only, an nft of pool.gate_collection held by the signer
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    pub gate_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+
+    // required in CooldownScope::Global pools and pools with pool.min_profile_age
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [USER_PROFILE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub user_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    // stakers only, shortens cooldowns by the launchpad stake tier and counts
+    // toward pool.min_gogr_staked
+    #[account(
+        seeds = [USER_STAKE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump = user_stake.bump
+    )]
+    pub user_stake: Option<Box<Account<'info, UserStake>>>,
+
+    // required while pool.tournament runs, see utils::record_tournament_entry
+    #[account(mut, address = pool.tournament @ LaunchpadErrorCode::TournamentErr)]
+    pub tournament: Option<Box<Account<'info, Tournament>>>,
+
+    /// CHECK: empty when the signer never entered, loaded in utils::record_tournament_entry
+    #[account(
+        mut,
+        seeds = [TOURNAMENT_ENTRY_SEED, pool.tournament.as_ref(), signer.key().as_ref()],
+        bump
+    )]
+    pub tournament_entry: Option<UncheckedAccount<'info>>,
+
+    // pools with wallet balance requirements only, see Mine::check_wallet
+    #[account(token::mint = launchpad.gogr_mint, token::authority = signer)]
+    pub user_gogr_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(token::mint = pool.token_mint, token::authority = signer)]
+    pub user_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: see utils::check_top_level and utils::check_attestation
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
+impl<'info> Mine<'info> {
+    fn check_attestation(&self, cur: i64) -> Result<()> {
+        if self.pool.attestation_signer == Pubkey::default() {
+            return Ok(());
+        }
+        check_attestation(
+            &self.instructions_sysvar,
+            &self.pool.attestation_signer,
+            &self.pool.key(),
+            &self.signer.key(),
+            cur,
+        )
+    }
+
+    fn check_wallet(&self, cur: i64) -> Result<()> {
+        let pool = &self.pool;
+        if pool.min_gogr_balance > 0 {
+            let user_gogr_account = self
+                .user_gogr_account
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::WalletRequirementErr)?;
+            require!(
+                user_gogr_account.amount >= pool.min_gogr_balance,
+                LaunchpadErrorCode::WalletRequirementErr
+            );
+        }
+        if pool.min_gogr_staked > 0 {
+            let user_stake = self
+                .user_stake
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::WalletRequirementErr)?;
+            require!(
+                user_stake.amount >= pool.min_gogr_staked,
+                LaunchpadErrorCode::WalletRequirementErr
+            );
+        }
+        if pool.min_token_balance > 0 {
+            let user_token_account = self
+                .user_token_account
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::WalletRequirementErr)?;
+            require!(
+                user_token_account.amount >= pool.min_token_balance,
+                LaunchpadErrorCode::WalletRequirementErr
+            );
+        }
+        if pool.min_profile_age > 0 {
+            // a profile created by this call is 0 seconds old and fails here
+            let user_profile = self
+                .user_profile
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::WalletRequirementErr)?;
+            let age = cur.saturating_sub(user_profile.created_at);
+            require!(
+                age >= pool.min_profile_age as i64,
+                LaunchpadErrorCode::WalletRequirementErr
+            );
+        }
+        Ok(())
+    }
+
+    fn check_gate(&self) -> Result<()> {
+        if self.pool.gate_collection == Pubkey::default() {
+            return Ok(());
+        }
+        let (Some(gate_token_account), Some(gate_metadata)) =
+            (&self.gate_token_account, &self.gate_metadata)
+        else {
+            return err!(LaunchpadErrorCode::NftGateErr);
+        };
+        require!(
+            gate_token_account.owner == self.signer.key() && gate_token_account.amount > 0,
+            LaunchpadErrorCode::NftGateErr
+        );
+        check_collection(
+            gate_metadata,
+            gate_token_account.mint,
+            self.pool.gate_collection,
+        )
+    }
+}
+
 pub fn mine_handler(ctx: Context<Mine>, args: MineArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_MINE)?;
+    check_top_level(&ctx.accounts.instructions_sysvar)?;
     let pool = &ctx.accounts.pool;
-    let game_info = &mut ctx.accounts.game_info;
 
+    require!(
+        pool.status == PoolStatus::Active,
+        LaunchpadErrorCode::PoolStatusErr
+    );
     require!(
         pool.available_token_amount > 0,
         LaunchpadErrorCode::PoolEndedErr
@@ -53,25 +197,57 @@ pub fn mine_handler(ctx: Context<Mine>, args: MineArgs) -> Result<()> {
 
     let clock = Clock::get()?;
     let cur = clock.unix_timestamp;
+    // a profile created by this call starts its age now, whatever the scope
+    if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+        user_profile.init(
+            ctx.bumps.user_profile,
+            ctx.accounts.launchpad.key(),
+            ctx.accounts.signer.key(),
+            cur,
+        );
+    }
+    // holders, attested and qualified wallets only, checked once per game
+    if args.step == 1 {
+        ctx.accounts.check_gate()?;
+        ctx.accounts.check_attestation(cur)?;
+        ctx.accounts.check_wallet(cur)?;
+    }
+    // global pools share one cooldown and one running game per wallet
+    let game_info_key = ctx.accounts.game_info.key();
+    if pool.cooldown_scope == CooldownScope::Global {
+        let user_profile = ctx
+            .accounts
+            .user_profile
+            .as_mut()
+            .ok_or(LaunchpadErrorCode::CooldownErr)?;
+        if args.step == 1 {
+            user_profile.start_game(game_info_key, cur)?;
+        }
+    }
+    let game_info = &mut ctx.accounts.game_info;
 
     // check status if a new round
     if args.step == 1 {
         // check cooldown_timestamp
         require!(
-            game_info.cooldown_timestamp == 0 || game_info.cooldown_timestamp <= cur as u32,
+            game_info.cooldown_timestamp == 0 || game_info.cooldown_timestamp <= cur,
             LaunchpadErrorCode::UserCoolDownPosErr
         );
 
         if game_info.game_counter > 0 {
             require!(
                 game_info.game_status == GameStatus::Collected
-                    || game_info.game_status == GameStatus::Exploded,
+                    || game_info.game_status == GameStatus::Exploded
+                    || game_info.game_status == GameStatus::Expired,
                 LaunchpadErrorCode::GameStatusErr
             );
             game_info.clear_data();
         } else {
             game_info.bump = ctx.bumps.game_info;
+            game_info.user = ctx.accounts.signer.key();
+            game_info.pool_id = pool.pool_id;
         }
+        game_info.set_start_cell(pool);
     } else {
         require!(
             game_info.step + 1 == args.step,
@@ -84,20 +260,56 @@ pub fn mine_handler(ctx: Context<Mine>, args: MineArgs) -> Result<()> {
     }
 
     //check pos
-    game_info.check_grid(args.pos_x, args.pos_y)?;
+    game_info.check_grid(pool, args.pos_x, args.pos_y)?;
 
     // update game_info
     game_info.step = args.step;
+    game_info.last_step_at = cur;
 
     let pool = &mut ctx.accounts.pool;
+    if args.step == 1 {
+        pool.start_game()?;
+    }
+
+    // the tier staked when the game ends applies, this step may explode
+    game_info.cooldown_reduction_bps = ctx.accounts.user_stake.as_ref().map_or(0, |s| {
+        ctx.accounts.launchpad.get_cooldown_reduction_bps(s.amount)
+    });
 
     let random = pool.get_vrf(cur as u64);
-    let step_statsu = game_info.handle_vrf(&ctx.accounts.pool, random)?;
+    let step_statsu = game_info.handle_vrf(&ctx.accounts.pool, random, cur)?;
     game_info.set_grid_mined(args.pos_x, args.pos_y, step_statsu);
+    if step_statsu == StepStatus::Nft {
+        let pool = &mut ctx.accounts.pool;
+        if let Some(nft_mint) = pool.reserve_nft(random.rotate_left(32)) {
+            game_info.nft_rewards.push(nft_mint);
+        }
+    }
+    record_tournament_entry(
+        &ctx.accounts.pool,
+        &mut ctx.accounts.tournament,
+        &ctx.accounts.tournament_entry,
+        cur,
+        |entry| entry.record_mine(args.step, step_statsu),
+    )?;
     if step_statsu == StepStatus::Exploded {
         let pool = &mut ctx.accounts.pool;
-        pool.game_count += 1;
-        game_info.game_counter += 1;
+        // reserved nfts go back to the pool
+        pool.nft_mints.append(&mut game_info.nft_rewards);
+        pool.end_game();
+        if pool.cooldown_scope == CooldownScope::Global {
+            if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+                user_profile.end_game(game_info.cooldown_timestamp);
+            }
+        }
+        pool.game_count = pool
+            .game_count
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        game_info.game_counter = game_info
+            .game_counter
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
     }
 
     Ok(())
@@ -4,6 +4,28 @@ pub mod update_pool;
 pub mod initialize;
 pub mod mine;
 pub mod update;
+pub mod propose_manager;
+pub mod accept_manager;
+pub mod close_pool;
+pub mod approve_pool;
+pub mod reject_pool;
+pub mod boost_pool;
+pub mod add_pool_reward;
+pub mod deposit_nft;
+pub mod claim_vested;
+pub mod register_referrer;
+pub mod set_pool_referral;
+pub mod claim_referral;
+pub mod reset_leaderboard;
+pub mod create_tournament;
+pub mod enter_tournament;
+pub mod settle_tournament;
+pub mod stake_gogr;
+pub mod unstake_gogr;
+pub mod skip_cooldown;
+pub mod withdraw_gogr;
+pub mod claim_staking_rewards;
+pub mod expire_game;
 
 pub use collect::*;
 pub use create_pool::*;
@@ -11,3 +33,25 @@ pub use update_pool::*;
 pub use initialize::*;
 pub use mine::*;
 pub use update::*;
+pub use propose_manager::*;
+pub use accept_manager::*;
+pub use close_pool::*;
+pub use approve_pool::*;
+pub use reject_pool::*;
+pub use boost_pool::*;
+pub use add_pool_reward::*;
+pub use deposit_nft::*;
+pub use claim_vested::*;
+pub use register_referrer::*;
+pub use set_pool_referral::*;
+pub use claim_referral::*;
+pub use reset_leaderboard::*;
+pub use create_tournament::*;
+pub use enter_tournament::*;
+pub use settle_tournament::*;
+pub use stake_gogr::*;
+pub use unstake_gogr::*;
+pub use skip_cooldown::*;
+pub use withdraw_gogr::*;
+pub use claim_staking_rewards::*;
+pub use expire_game::*;
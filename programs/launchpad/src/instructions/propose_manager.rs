@@ -0,0 +1,35 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProposeManagerArgs {
+    pub new_manager: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct ProposeManager<'info> {
+    pub manager: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump,
+        has_one = manager @ LaunchpadErrorCode::AdminErr
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+}
+
+pub fn propose_manager_handler(
+    ctx: Context<ProposeManager>,
+    args: ProposeManagerArgs,
+) -> Result<()> {
+    require!(
+        args.new_manager != Pubkey::default(),
+        LaunchpadErrorCode::ManagerErr
+    );
+    let launchpad = &mut ctx.accounts.launchpad;
+    launchpad.pending_manager = args.new_manager;
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::constants::PAUSE_MINE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RegisterReferrerArgs {
+    pub referrer: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [
+            USER_PROFILE_SEED,
+            launchpad.key().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump,
+        space = 8 + UserProfile::INIT_SPACE
+    )]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_referrer_handler(
+    ctx: Context<RegisterReferrer>,
+    args: RegisterReferrerArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_MINE)?;
+    let user_profile = &mut ctx.accounts.user_profile;
+    let signer = ctx.accounts.signer.key();
+    user_profile.init(
+        ctx.bumps.user_profile,
+        ctx.accounts.launchpad.key(),
+        signer,
+        Clock::get()?.unix_timestamp,
+    );
+
+    // the referrer can only be set once
+    require!(
+        user_profile.referrer == Pubkey::default(),
+        LaunchpadErrorCode::ReferrerErr
+    );
+    require!(
+        args.referrer != Pubkey::default() && args.referrer != signer,
+        LaunchpadErrorCode::ReferrerErr
+    );
+    user_profile.referrer = args.referrer;
+    Ok(())
+}
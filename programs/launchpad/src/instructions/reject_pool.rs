@@ -0,0 +1,209 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+use crate::utils::{transfer_from_pool, transfer_pool_rewards, withdraw_pool_token};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RejectPoolArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: RejectPoolArgs)]
+pub struct RejectPool<'info> {
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump,
+        has_one = manager @ LaunchpadErrorCode::AdminErr
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TOKEN_EXIST_SEED,
+            launchpad.key().as_ref(),
+            pool.token_mint.as_ref(),
+            pool.owner.as_ref()
+        ],
+        bump = token_exist.bump
+    )]
+    pub token_exist: Box<Account<'info, TokenExist>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(address = launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK:
+    #[account(address = launchpad.gogr_receiver)]
+    pub gogr_receiver: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = gogr_receiver
+    )]
+    pub gogr_receiver_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_gogr_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_gogr_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(address = pool.token_mint)]
+    pub pool_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = owner
+    )]
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = pool_token_mint,
+        associated_token::authority = pool
+    )]
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> RejectPool<'info> {
+    fn pool_gogr_account_info(&self) -> Result<AccountInfo<'info>> {
+        let pool_gogr_account = self
+            .pool_gogr_account
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::ParamErr)?;
+        Ok(pool_gogr_account.to_account_info())
+    }
+}
+
+pub fn reject_pool_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RejectPool<'info>>,
+    _args: RejectPoolArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    pool.status = PoolStatus::Rejected;
+
+    let token_amt = pool
+        .available_token_amount
+        .checked_add(pool.available_bonus_amount)
+        .and_then(|v| v.checked_add(pool.available_referral_amount))
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    pool.available_token_amount = 0;
+    pool.available_bonus_amount = 0;
+    pool.available_referral_amount = 0;
+    let mut rewards = Vec::with_capacity(pool.extra_rewards.len() + pool.nft_mints.len());
+    for reward in pool.extra_rewards.iter_mut() {
+        rewards.push((reward.mint, reward.available_token_amount));
+        reward.available_token_amount = 0;
+    }
+    rewards.extend(pool.take_nfts().into_iter().map(|mint| (mint, 1)));
+    let gogr_amt = pool.gogr_escrow;
+    pool.gogr_escrow = 0;
+    pool.boost_escrow = 0;
+
+    let token_exist = &mut ctx.accounts.token_exist;
+    if token_exist.active_pool == pool.key() {
+        token_exist.active_pool = Pubkey::default();
+    }
+
+    // refund the pool tokens and the configured share of gogr, the rest goes to the receiver
+    let accounts = &ctx.accounts;
+    if token_amt > 0 {
+        withdraw_pool_token(
+            &accounts.pool,
+            &accounts.pool_token_account,
+            &accounts.token_owner_account,
+            &accounts.sol_vault,
+            accounts.owner.to_account_info(),
+            &accounts.token_program,
+            &accounts.system_program,
+            token_amt,
+        )?;
+    }
+    if gogr_amt > 0 {
+        let refund_amt = accounts.launchpad.get_refund_amt(gogr_amt)?;
+        let left_amt = gogr_amt
+            .checked_sub(refund_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        if refund_amt > 0 {
+            transfer_from_pool(
+                &accounts.pool,
+                &accounts.token_program,
+                accounts.pool_gogr_account_info()?,
+                accounts.owner_gogr_account.to_account_info(),
+                refund_amt,
+            )?;
+        }
+        if left_amt > 0 {
+            transfer_from_pool(
+                &accounts.pool,
+                &accounts.token_program,
+                accounts.pool_gogr_account_info()?,
+                accounts.gogr_receiver_account.to_account_info(),
+                left_amt,
+            )?;
+        }
+    }
+    transfer_pool_rewards(
+        &ctx.accounts.pool,
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+        ctx.accounts.owner.key(),
+        &rewards,
+    )
+}
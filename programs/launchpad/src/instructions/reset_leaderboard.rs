@@ -0,0 +1,51 @@
+use crate::constants::PAUSE_CREATE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ResetLeaderboardArgs {
+    pub pool_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: ResetLeaderboardArgs)]
+pub struct ResetLeaderboard<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED, pool.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+}
+
+// starts a new leaderboard season for the pool
+pub fn reset_leaderboard_handler(
+    ctx: Context<ResetLeaderboard>,
+    _args: ResetLeaderboardArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let clock = Clock::get()?;
+    ctx.accounts.leaderboard.reset(clock.unix_timestamp)
+}
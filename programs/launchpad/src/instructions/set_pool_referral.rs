@@ -0,0 +1,71 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_REFERRAL_BPS, PAUSE_CREATE};
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SetPoolReferralArgs {
+    pub pool_id: u64,
+    pub referral_bps: u16,
+    // moved from available_token_amount into the referral sub-pool
+    pub token_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SetPoolReferralArgs)]
+pub struct SetPoolReferral<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump,
+        has_one = owner @ LaunchpadErrorCode::PoolOwnerErr
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+}
+
+pub fn set_pool_referral_handler(
+    ctx: Context<SetPoolReferral>,
+    args: SetPoolReferralArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
+    require!(
+        args.referral_bps <= MAX_REFERRAL_BPS,
+        LaunchpadErrorCode::ReferralRateErr
+    );
+    pool.referral_bps = args.referral_bps;
+
+    if args.token_amount > 0 {
+        pool.available_token_amount = pool
+            .available_token_amount
+            .checked_sub(args.token_amount)
+            .ok_or(LaunchpadErrorCode::TokenAmtErr)?;
+        pool.referral_token_amount = pool
+            .referral_token_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_referral_amount = pool
+            .available_referral_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SettleTournamentArgs {
+    pub tournament_id: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SettleTournamentArgs)]
+pub struct SettleTournament<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [
+            TOURNAMENT_SEED,
+            launchpad.key().as_ref(),
+            args.tournament_id.to_be_bytes().as_ref()
+        ],
+        bump = tournament.bump
+    )]
+    pub tournament: Box<Account<'info, Tournament>>,
+
+    #[account(address = tournament.prize_mint)]
+    pub prize_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = prize_mint,
+        associated_token::authority = tournament
+    )]
+    pub tournament_prize_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK:
+    #[account(address = tournament.creator)]
+    pub creator: UncheckedAccount<'info>,
+
+    // unpaid ranks go back to the creator
+    #[account(
+        mut,
+        associated_token::mint = prize_mint,
+        associated_token::authority = creator
+    )]
+    pub creator_prize_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+impl<'info> SettleTournament<'info> {
+    fn transfer_prize(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        let tournament_id_bytes = self.tournament.tournament_id.to_be_bytes();
+        let signer_seeds = [
+            TOURNAMENT_SEED,
+            self.tournament.launchpad.as_ref(),
+            tournament_id_bytes.as_ref(),
+            &[self.tournament.bump],
+        ];
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to,
+                    from: self.tournament_prize_account.to_account_info(),
+                    authority: self.tournament.to_account_info(),
+                },
+            )
+            .with_signer(&[&signer_seeds]),
+            amount,
+        )
+    }
+}
+
+// remaining accounts are the prize token accounts of tournament.standings, in rank order.
+// ranks without a score win nothing, their share goes back to the creator
+pub fn settle_tournament_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleTournament<'info>>,
+    _args: SettleTournamentArgs,
+) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let tournament = &ctx.accounts.tournament;
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= tournament.end_ts,
+        LaunchpadErrorCode::TournamentTimeErr
+    );
+    require!(!tournament.settled, LaunchpadErrorCode::TournamentErr);
+
+    let mut paid_amt: u64 = 0;
+    for (rank, standing) in tournament.standings.iter().enumerate() {
+        let amt = tournament.get_prize_amt(rank)?;
+        if amt == 0 || standing.score == 0 {
+            continue;
+        }
+        let to = ctx
+            .remaining_accounts
+            .get(rank)
+            .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+        require_keys_eq!(
+            *to.owner,
+            ctx.accounts.token_program.key(),
+            LaunchpadErrorCode::RewardAccountErr
+        );
+        let to_account = TokenAccount::try_deserialize(&mut &to.data.borrow()[..])?;
+        require!(
+            to_account.mint == tournament.prize_mint && to_account.owner == standing.user,
+            LaunchpadErrorCode::RewardAccountErr
+        );
+        ctx.accounts.transfer_prize(to.clone(), amt)?;
+        paid_amt = paid_amt
+            .checked_add(amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    }
+
+    let left_amt = tournament
+        .prize_amount
+        .checked_sub(paid_amt)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    if left_amt > 0 {
+        ctx.accounts.transfer_prize(
+            ctx.accounts.creator_prize_account.to_account_info(),
+            left_amt,
+        )?;
+    }
+    ctx.accounts.tournament.settled = true;
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::constants::PAUSE_MINE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{burn, Burn, Mint, Token, TokenAccount},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SkipCooldownArgs {
+    pub pool_id: u64,
+    // slippage guard, the burn follows the cooldown left at execution
+    pub max_gogr_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SkipCooldownArgs)]
+pub struct SkipCooldown<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        seeds = [
+            POOL_INFO_SEED,
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
+        ],
+        bump = pool.bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        seeds = [
+            GAME_INFO_SEED,
+            launchpad.key().as_ref(),
+            pool.pool_id.to_be_bytes().as_ref(),
+            signer.key().as_ref()
+        ],
+        bump = game_info.bump
+    )]
+    pub game_info: Box<Account<'info, GameInfo>>,
+
+    // global pools, the wallet cooldown is skipped along with the game one
+    #[account(
+        mut,
+        seeds = [USER_PROFILE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Option<Box<Account<'info, UserProfile>>>,
+
+    #[account(mut, address=launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = signer
+    )]
+    pub gogr_from_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+impl<'info> SkipCooldown<'info> {
+    fn burn_gogr(&self, amount: u64) -> Result<()> {
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.gogr_mint.to_account_info(),
+                    from: self.gogr_from_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+pub fn skip_cooldown_handler(ctx: Context<SkipCooldown>, args: SkipCooldownArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_MINE)?;
+    let launchpad = &ctx.accounts.launchpad;
+    require!(
+        launchpad.skip_cooldown_rate > 0,
+        LaunchpadErrorCode::CooldownErr
+    );
+
+    let clock = Clock::get()?;
+    let cur = clock.unix_timestamp;
+    let game_info = &mut ctx.accounts.game_info;
+    let mut cooldown_timestamp = game_info.cooldown_timestamp;
+    if let Some(user_profile) = ctx.accounts.user_profile.as_mut() {
+        cooldown_timestamp = cooldown_timestamp.max(user_profile.cooldown_timestamp);
+        user_profile.cooldown_timestamp = user_profile.cooldown_timestamp.min(cur);
+    }
+    require!(cooldown_timestamp > cur, LaunchpadErrorCode::CooldownErr);
+
+    let burn_amt = launchpad.get_skip_cooldown_amt(cooldown_timestamp - cur)?;
+    require!(
+        burn_amt <= args.max_gogr_amount,
+        LaunchpadErrorCode::CooldownErr
+    );
+    game_info.cooldown_timestamp = game_info.cooldown_timestamp.min(cur);
+
+    ctx.accounts.burn_gogr(burn_amt)
+}
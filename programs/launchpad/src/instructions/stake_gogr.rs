@@ -0,0 +1,100 @@
+use crate::constants::PAUSE_CREATE;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct StakeGogrArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct StakeGogr<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(address=launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = signer
+    )]
+    pub gogr_from_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump,
+        token::mint = gogr_mint,
+        token::authority = launchpad
+    )]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [USER_STAKE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump,
+        space = 8 + UserStake::INIT_SPACE
+    )]
+    pub user_stake: Box<Account<'info, UserStake>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> StakeGogr<'info> {
+    fn transfer_gogr(&self, amount: u64) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to: self.stake_vault.to_account_info(),
+                    from: self.gogr_from_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+}
+
+pub fn stake_gogr_handler(ctx: Context<StakeGogr>, args: StakeGogrArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
+    require!(args.amount > 0, LaunchpadErrorCode::StakeErr);
+    let launchpad = &mut ctx.accounts.launchpad;
+    let user_stake = &mut ctx.accounts.user_stake;
+    if user_stake.user == Pubkey::default() {
+        user_stake.bump = ctx.bumps.user_stake;
+        user_stake.launchpad = launchpad.key();
+        user_stake.user = ctx.accounts.signer.key();
+    }
+    user_stake.settle_reward(launchpad.acc_reward_per_share)?;
+    user_stake.amount = user_stake
+        .amount
+        .checked_add(args.amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    user_stake.reset_reward_debt(launchpad.acc_reward_per_share)?;
+    launchpad.total_staked = launchpad
+        .total_staked
+        .checked_add(args.amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    ctx.accounts.transfer_gogr(args.amount)
+}
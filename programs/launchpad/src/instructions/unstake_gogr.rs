@@ -0,0 +1,59 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UnstakeGogrArgs {
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct UnstakeGogr<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(
+        mut,
+        seeds = [USER_STAKE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump = user_stake.bump
+    )]
+    pub user_stake: Box<Account<'info, UserStake>>,
+}
+
+// unstaked gogr stops earning right away and can be withdrawn after unbonding_period
+pub fn unstake_gogr_handler(ctx: Context<UnstakeGogr>, args: UnstakeGogrArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    require!(args.amount > 0, LaunchpadErrorCode::StakeErr);
+    let launchpad = &mut ctx.accounts.launchpad;
+    let user_stake = &mut ctx.accounts.user_stake;
+    user_stake.settle_reward(launchpad.acc_reward_per_share)?;
+    user_stake.amount = user_stake
+        .amount
+        .checked_sub(args.amount)
+        .ok_or(LaunchpadErrorCode::StakeErr)?;
+    user_stake.reset_reward_debt(launchpad.acc_reward_per_share)?;
+    launchpad.total_staked = launchpad
+        .total_staked
+        .checked_sub(args.amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+
+    let clock = Clock::get()?;
+    user_stake.unbonding_amount = user_stake
+        .unbonding_amount
+        .checked_add(args.amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    user_stake.unbonding_end_ts = clock
+        .unix_timestamp
+        .checked_add(launchpad.unbonding_period as i64)
+        .ok_or(LaunchpadErrorCode::TimestampOverflow)?;
+    Ok(())
+}
@@ -9,8 +9,15 @@ use crate::state::*;
 pub struct UpdateArgs {
     pub gogr_fee: Option<u64>,
     pub gogr_receiver: Option<Pubkey>,
-    pub manager: Option<Pubkey>,
     pub burn_rate: Option<u8>,
+    pub approval_required: Option<bool>,
+    pub reject_refund_rate: Option<u8>,
+    pub skip_cooldown_rate: Option<u64>,
+    pub stake_tiers: Option<Vec<StakeTier>>,
+    pub staking_fee_rate: Option<u8>,
+    pub unbonding_period: Option<u32>,
+    pub collect_fee: Option<u64>,
+    pub paused: Option<u8>,
 
 }
 
@@ -20,7 +27,7 @@ pub struct Update<'info> {
     pub manager: Signer<'info>,
     #[account(
         mut,
-        seeds = [LAUNCHPAD_CONFIG_SEED],
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
         bump = launchpad.bump, 
         has_one = manager @ LaunchpadErrorCode::AdminErr
     )]
@@ -37,11 +44,32 @@ pub fn update_handler(ctx: Context<Update>, args: UpdateArgs) -> Result<()> {
     if let Some(gogr_receiver) = args.gogr_receiver {
         launchpad.gogr_receiver = gogr_receiver;
     }
-    if let Some(manager) = args.manager {
-        launchpad.manager = manager;
-    }
     if let Some(burn_rate) = args.burn_rate {
         launchpad.burn_rate = burn_rate;
     }
-    Ok(())
+    if let Some(approval_required) = args.approval_required {
+        launchpad.approval_required = approval_required;
+    }
+    if let Some(reject_refund_rate) = args.reject_refund_rate {
+        launchpad.reject_refund_rate = reject_refund_rate;
+    }
+    if let Some(skip_cooldown_rate) = args.skip_cooldown_rate {
+        launchpad.skip_cooldown_rate = skip_cooldown_rate;
+    }
+    if let Some(stake_tiers) = args.stake_tiers {
+        launchpad.stake_tiers = stake_tiers;
+    }
+    if let Some(staking_fee_rate) = args.staking_fee_rate {
+        launchpad.staking_fee_rate = staking_fee_rate;
+    }
+    if let Some(unbonding_period) = args.unbonding_period {
+        launchpad.unbonding_period = unbonding_period;
+    }
+    if let Some(collect_fee) = args.collect_fee {
+        launchpad.collect_fee = collect_fee;
+    }
+    if let Some(paused) = args.paused {
+        launchpad.paused = paused;
+    }
+    launchpad.validate()
 }
@@ -1,3 +1,5 @@
+use crate::constants::PAUSE_CREATE;
+use crate::errors::LaunchpadErrorCode;
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -6,11 +8,14 @@ use anchor_spl::{
 
 use crate::seeds::*;
 use crate::state::*;
+use crate::utils::{add_pool_boost, deposit_sol, GogrPayment};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdatePoolArgs {
+    pub pool_id: u64,
     pub gogr_ext: u64,
     pub token_amount: u64,
+    pub bonus_token_amount: u64,
 }
 
 #[derive(Accounts)]
@@ -19,20 +24,34 @@ pub struct UpdatePool<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    #[account(mut,seeds = [LAUNCHPAD_CONFIG_SEED],bump=launchpad.bump)]
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
     pub launchpad: Box<Account<'info, Launchpad>>,
 
     #[account(
         mut,
         seeds = [
             POOL_INFO_SEED,
-            launchpad.last_pool_id.to_be_bytes().as_ref()
+            launchpad.key().as_ref(),
+            args.pool_id.to_be_bytes().as_ref()
             ],
-        bump)
+        bump = pool.bump)
     ]
     pub pool: Box<Account<'info, Pool>>,
 
-    #[account(address=launchpad.gogr_mint)]
+    #[account(
+        init_if_needed,
+        payer = signer,
+        seeds = [FEATURED_POOLS_SEED, launchpad.key().as_ref()],
+        bump,
+        space = 8 + FeaturedPools::INIT_SPACE
+    )]
+    pub featured_pools: Box<Account<'info, FeaturedPools>>,
+
+    #[account(mut, address=launchpad.gogr_mint)]
     pub gogr_mint: Box<Account<'info, Mint>>,
 
     /// CHECK:
@@ -54,7 +73,15 @@ pub struct UpdatePool<'info> {
     )]
     pub gogr_receiver_account: Box<Account<'info, TokenAccount>>,
 
-    #[account()]
+    // required while gogr is staked, see Launchpad::add_staking_reward
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(address = pool.token_mint)]
     pub pool_token_mint: Box<Account<'info, Mint>>,
 
     #[account(
@@ -62,7 +89,7 @@ pub struct UpdatePool<'info> {
         associated_token::mint = pool_token_mint,
         associated_token::authority = signer
     )]
-    pub token_owner_account: Box<Account<'info, TokenAccount>>,
+    pub token_owner_account: Option<Box<Account<'info, TokenAccount>>>,
 
     #[account(
         init_if_needed,
@@ -70,34 +97,40 @@ pub struct UpdatePool<'info> {
         associated_token::mint = pool_token_mint,
         associated_token::authority = pool
     )]
-    pub pool_token_account: Box<Account<'info, TokenAccount>>,
+    pub pool_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    // sol pools only, see Pool::is_sol
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, pool.key().as_ref()],
+        bump = pool.sol_vault_bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 impl<'info> UpdatePool<'info> {
-    fn transfer_gogr(&self, amount: u64) -> Result<()> {
-        transfer(
-            CpiContext::new(
-                self.token_program.to_account_info(),
-                Transfer {
-                    to: self.gogr_receiver_account.to_account_info(),
-                    from: self.gogr_from_account.to_account_info(),
-                    authority: self.signer.to_account_info(),
-                },
-            ),
-            amount,
-        )
-    }
-
     fn transfer_pool_token(&self, amount: u64) -> Result<()> {
+        if self.pool.is_sol() {
+            let sol_vault = self
+                .sol_vault
+                .as_ref()
+                .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+            return deposit_sol(&self.signer, sol_vault, &self.system_program, amount);
+        }
+        let (Some(pool_token_account), Some(token_owner_account)) =
+            (&self.pool_token_account, &self.token_owner_account)
+        else {
+            return err!(LaunchpadErrorCode::RewardAccountErr);
+        };
         transfer(
             CpiContext::new(
                 self.token_program.to_account_info(),
                 Transfer {
-                    to: self.pool_token_account.to_account_info(),
-                    from: self.token_owner_account.to_account_info(),
+                    to: pool_token_account.to_account_info(),
+                    from: token_owner_account.to_account_info(),
                     authority: self.signer.to_account_info(),
                 },
             ),
@@ -107,19 +140,66 @@ impl<'info> UpdatePool<'info> {
 }
 
 pub fn update_pool_handler(ctx: Context<UpdatePool>, args: UpdatePoolArgs) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_CREATE)?;
     let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::PendingReview,
+        LaunchpadErrorCode::PoolStatusErr
+    );
 
+    // the same boost as boost_pool, only for live pools since a rejected pool
+    // refunds just its create_pool escrow
     if args.gogr_ext > 0 {
-        pool.gogr_ext += args.gogr_ext;
-        ctx.accounts.transfer_gogr(args.gogr_ext)?;
+        require!(
+            pool.status == PoolStatus::Active,
+            LaunchpadErrorCode::PoolStatusErr
+        );
+        let clock = Clock::get()?;
+        let featured_pools = &mut ctx.accounts.featured_pools;
+        featured_pools.init(ctx.bumps.featured_pools, ctx.accounts.launchpad.key());
+        let payment = GogrPayment {
+            signer: &ctx.accounts.signer,
+            gogr_mint: &ctx.accounts.gogr_mint,
+            gogr_from_account: &ctx.accounts.gogr_from_account,
+            gogr_receiver_account: &ctx.accounts.gogr_receiver_account,
+            stake_vault: ctx.accounts.stake_vault.as_deref(),
+            token_program: &ctx.accounts.token_program,
+        };
+        add_pool_boost(
+            pool,
+            featured_pools,
+            &mut ctx.accounts.launchpad,
+            &payment,
+            args.gogr_ext,
+            clock.unix_timestamp,
+        )?;
     }
     let pool = &mut ctx.accounts.pool;
 
     // transfer gogr
     if args.token_amount > 0 {
-        pool.token_amount += args.token_amount;
-        pool.available_token_amount += args.token_amount;
+        pool.token_amount = pool
+            .token_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_token_amount = pool
+            .available_token_amount
+            .checked_add(args.token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
         ctx.accounts.transfer_pool_token(args.token_amount)?;
     }
+    let pool = &mut ctx.accounts.pool;
+
+    if args.bonus_token_amount > 0 {
+        pool.bonus_token_amount = pool
+            .bonus_token_amount
+            .checked_add(args.bonus_token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        pool.available_bonus_amount = pool
+            .available_bonus_amount
+            .checked_add(args.bonus_token_amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        ctx.accounts.transfer_pool_token(args.bonus_token_amount)?;
+    }
     Ok(())
 }
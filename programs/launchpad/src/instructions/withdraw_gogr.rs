@@ -0,0 +1,90 @@
+use crate::constants::PAUSE_COLLECT;
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{transfer, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::seeds::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct WithdrawGogr<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LAUNCHPAD_CONFIG_SEED, launchpad.launchpad_id.to_be_bytes().as_ref()],
+        bump = launchpad.bump
+    )]
+    pub launchpad: Box<Account<'info, Launchpad>>,
+
+    #[account(address=launchpad.gogr_mint)]
+    pub gogr_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = gogr_mint,
+        associated_token::authority = signer
+    )]
+    pub gogr_to_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_VAULT_SEED, launchpad.key().as_ref()],
+        bump
+    )]
+    pub stake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [USER_STAKE_SEED, launchpad.key().as_ref(), signer.key().as_ref()],
+        bump = user_stake.bump
+    )]
+    pub user_stake: Box<Account<'info, UserStake>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+impl<'info> WithdrawGogr<'info> {
+    fn transfer_gogr(&self, amount: u64) -> Result<()> {
+        let launchpad_id_bytes = self.launchpad.launchpad_id.to_be_bytes();
+        let signer_seeds = [
+            LAUNCHPAD_CONFIG_SEED,
+            launchpad_id_bytes.as_ref(),
+            &[self.launchpad.bump],
+        ];
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to: self.gogr_to_account.to_account_info(),
+                    from: self.stake_vault.to_account_info(),
+                    authority: self.launchpad.to_account_info(),
+                },
+            )
+            .with_signer(&[&signer_seeds]),
+            amount,
+        )
+    }
+}
+
+// pays out gogr whose unbonding period is over
+pub fn withdraw_gogr_handler(ctx: Context<WithdrawGogr>) -> Result<()> {
+    ctx.accounts.launchpad.check_paused(PAUSE_COLLECT)?;
+    let user_stake = &mut ctx.accounts.user_stake;
+    let amt = user_stake.unbonding_amount;
+    require!(amt > 0, LaunchpadErrorCode::UnbondingErr);
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= user_stake.unbonding_end_ts,
+        LaunchpadErrorCode::UnbondingErr
+    );
+    user_stake.unbonding_amount = 0;
+
+    ctx.accounts.transfer_gogr(amt)
+}
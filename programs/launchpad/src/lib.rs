@@ -7,6 +7,7 @@ pub mod errors;
 pub mod instructions;
 pub mod seeds;
 pub mod state;
+pub mod utils;
 use instructions::*;
 
 #[program]
@@ -29,11 +30,126 @@ pub mod launchpad {
         instructions::mine_handler(ctx, args)
     }
 
-    pub fn collect(ctx: Context<Collect>, args: CollectArgs) -> Result<()> {
+    pub fn collect<'info>(
+        ctx: Context<'_, '_, '_, 'info, Collect<'info>>,
+        args: CollectArgs,
+    ) -> Result<()> {
         instructions::collect_handler(ctx, args)
     }
 
     pub fn update(ctx: Context<Update>, args: UpdateArgs) -> Result<()> {
         instructions::update_handler(ctx, args)
     }
+
+    pub fn propose_manager(ctx: Context<ProposeManager>, args: ProposeManagerArgs) -> Result<()> {
+        instructions::propose_manager_handler(ctx, args)
+    }
+
+    pub fn accept_manager(ctx: Context<AcceptManager>) -> Result<()> {
+        instructions::accept_manager_handler(ctx)
+    }
+
+    pub fn close_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClosePool<'info>>,
+        args: ClosePoolArgs,
+    ) -> Result<()> {
+        instructions::close_pool_handler(ctx, args)
+    }
+
+    pub fn approve_pool(ctx: Context<ApprovePool>, args: ApprovePoolArgs) -> Result<()> {
+        instructions::approve_pool_handler(ctx, args)
+    }
+
+    pub fn reject_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, RejectPool<'info>>,
+        args: RejectPoolArgs,
+    ) -> Result<()> {
+        instructions::reject_pool_handler(ctx, args)
+    }
+
+    pub fn boost_pool(ctx: Context<BoostPool>, args: BoostPoolArgs) -> Result<()> {
+        instructions::boost_pool_handler(ctx, args)
+    }
+
+    pub fn add_pool_reward(ctx: Context<AddPoolReward>, args: AddPoolRewardArgs) -> Result<()> {
+        instructions::add_pool_reward_handler(ctx, args)
+    }
+
+    pub fn deposit_nft(ctx: Context<DepositNft>, args: DepositNftArgs) -> Result<()> {
+        instructions::deposit_nft_handler(ctx, args)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>, args: ClaimVestedArgs) -> Result<()> {
+        instructions::claim_vested_handler(ctx, args)
+    }
+
+    pub fn register_referrer(
+        ctx: Context<RegisterReferrer>,
+        args: RegisterReferrerArgs,
+    ) -> Result<()> {
+        instructions::register_referrer_handler(ctx, args)
+    }
+
+    pub fn set_pool_referral(
+        ctx: Context<SetPoolReferral>,
+        args: SetPoolReferralArgs,
+    ) -> Result<()> {
+        instructions::set_pool_referral_handler(ctx, args)
+    }
+
+    pub fn claim_referral(ctx: Context<ClaimReferral>, args: ClaimReferralArgs) -> Result<()> {
+        instructions::claim_referral_handler(ctx, args)
+    }
+
+    pub fn reset_leaderboard(
+        ctx: Context<ResetLeaderboard>,
+        args: ResetLeaderboardArgs,
+    ) -> Result<()> {
+        instructions::reset_leaderboard_handler(ctx, args)
+    }
+
+    pub fn create_tournament<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateTournament<'info>>,
+        args: CreateTournamentArgs,
+    ) -> Result<()> {
+        instructions::create_tournament_handler(ctx, args)
+    }
+
+    pub fn enter_tournament(
+        ctx: Context<EnterTournament>,
+        args: EnterTournamentArgs,
+    ) -> Result<()> {
+        instructions::enter_tournament_handler(ctx, args)
+    }
+
+    pub fn settle_tournament<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleTournament<'info>>,
+        args: SettleTournamentArgs,
+    ) -> Result<()> {
+        instructions::settle_tournament_handler(ctx, args)
+    }
+
+    pub fn stake_gogr(ctx: Context<StakeGogr>, args: StakeGogrArgs) -> Result<()> {
+        instructions::stake_gogr_handler(ctx, args)
+    }
+
+    pub fn unstake_gogr(ctx: Context<UnstakeGogr>, args: UnstakeGogrArgs) -> Result<()> {
+        instructions::unstake_gogr_handler(ctx, args)
+    }
+
+    pub fn skip_cooldown(ctx: Context<SkipCooldown>, args: SkipCooldownArgs) -> Result<()> {
+        instructions::skip_cooldown_handler(ctx, args)
+    }
+
+    pub fn withdraw_gogr(ctx: Context<WithdrawGogr>) -> Result<()> {
+        instructions::withdraw_gogr_handler(ctx)
+    }
+
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+        instructions::claim_staking_rewards_handler(ctx)
+    }
+
+    pub fn expire_game(ctx: Context<ExpireGame>, args: ExpireGameArgs) -> Result<()> {
+        instructions::expire_game_handler(ctx, args)
+    }
 }
@@ -24,3 +24,32 @@ pub const USER_GAME_INFO_SEED: &[u8] = b"user-game-info";
 #[constant]
 pub const TOKEN_EXIST_SEED: &[u8] = b"token-exist";
 
+#[constant]
+pub const FEATURED_POOLS_SEED: &[u8] = b"featured-pools";
+
+#[constant]
+pub const SOL_VAULT_SEED: &[u8] = b"sol-vault";
+
+#[constant]
+pub const VESTING_SEED: &[u8] = b"vesting";
+
+#[constant]
+pub const USER_PROFILE_SEED: &[u8] = b"user-profile";
+
+#[constant]
+pub const REFERRAL_SEED: &[u8] = b"referral";
+
+#[constant]
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+
+#[constant]
+pub const TOURNAMENT_SEED: &[u8] = b"tournament";
+
+#[constant]
+pub const TOURNAMENT_ENTRY_SEED: &[u8] = b"tournament-entry";
+
+#[constant]
+pub const STAKE_VAULT_SEED: &[u8] = b"stake-vault";
+
+#[constant]
+pub const USER_STAKE_SEED: &[u8] = b"user-stake";
@@ -1,26 +1,165 @@
 use crate::constants::*;
 use crate::errors::LaunchpadErrorCode;
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token::spl_token::native_mint;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Launchpad {
     pub bump: u8,
+    pub launchpad_id: u64,
     pub last_pool_id: u64,
     pub gogr_mint: Pubkey,
     pub gogr_fee: u64,
     pub gogr_receiver: Pubkey,
     pub manager: Pubkey,
     pub burn_rate: u8,
+    pub pending_manager: Pubkey,
+    pub deployer: Pubkey,
+    // new pools wait for the manager to approve them
+    pub approval_required: bool,
+    // percentage of the escrowed gogr refunded on reject
+    pub reject_refund_rate: u8,
+    pub last_tournament_id: u64,
+    // gogr burned by skip_cooldown per second of cooldown left
+    pub skip_cooldown_rate: u64,
+    // ascending by min_amount, the highest tier reached applies
+    #[max_len(MAX_STAKE_TIERS)]
+    pub stake_tiers: Vec<StakeTier>,
+    pub total_staked: u64,
+    // percentage of the gogr fees left after burning that goes to stakers
+    pub staking_fee_rate: u8,
+    pub unbonding_period: u32,
+    // gogr charged on every collect, 0 disables it
+    pub collect_fee: u64,
+    // staking rewards per staked gogr, scaled by REWARD_PRECISION
+    pub acc_reward_per_share: u128,
+    // PAUSE_* bitflags set by the manager
+    pub paused: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub struct StakeTier {
+    pub min_amount: u64,
+    pub cooldown_reduction_bps: u16,
+}
+
+impl Launchpad {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.burn_rate <= MAX_BURN_RATE,
+            LaunchpadErrorCode::BurnRateErr
+        );
+        require!(
+            self.reject_refund_rate <= MAX_REFUND_RATE,
+            LaunchpadErrorCode::RefundRateErr
+        );
+        require!(
+            self.gogr_fee <= MAX_GOGR_FEE,
+            LaunchpadErrorCode::GogrFeeErr
+        );
+        require!(
+            self.gogr_mint != Pubkey::default(),
+            LaunchpadErrorCode::GogrMintErr
+        );
+        require!(
+            self.gogr_receiver != Pubkey::default(),
+            LaunchpadErrorCode::GogrReceiverErr
+        );
+        require!(
+            self.manager != Pubkey::default(),
+            LaunchpadErrorCode::ManagerErr
+        );
+        require!(
+            self.staking_fee_rate <= MAX_STAKING_FEE_RATE,
+            LaunchpadErrorCode::StakingFeeRateErr
+        );
+        require!(
+            self.collect_fee <= MAX_GOGR_FEE,
+            LaunchpadErrorCode::GogrFeeErr
+        );
+        require!(
+            self.stake_tiers.len() <= MAX_STAKE_TIERS
+                && self
+                    .stake_tiers
+                    .iter()
+                    .all(|t| t.cooldown_reduction_bps <= MAX_COOLDOWN_REDUCTION_BPS)
+                && self
+                    .stake_tiers
+                    .windows(2)
+                    .all(|w| w[0].min_amount < w[1].min_amount),
+            LaunchpadErrorCode::StakeTierErr
+        );
+        require!(
+            self.paused & !PAUSE_ALL == 0,
+            LaunchpadErrorCode::PauseFlagErr
+        );
+        Ok(())
+    }
+
+    pub fn check_paused(&self, flag: u8) -> Result<()> {
+        require!(self.paused & flag == 0, LaunchpadErrorCode::ProgramPaused);
+        Ok(())
+    }
+
+    pub fn get_cooldown_reduction_bps(&self, staked_amount: u64) -> u16 {
+        self.stake_tiers
+            .iter()
+            .rev()
+            .find(|t| staked_amount >= t.min_amount)
+            .map_or(0, |t| t.cooldown_reduction_bps)
+    }
+
+    // stakers' share of a gogr fee, nothing while no gogr is staked
+    pub fn add_staking_reward(&mut self, fee_amt: u64) -> Result<u64> {
+        if self.total_staked == 0 {
+            return Ok(0);
+        }
+        let amt = fee_amt
+            .checked_mul(self.staking_fee_rate as u64)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?
+            / 100;
+        self.acc_reward_per_share = (amt as u128)
+            .checked_mul(REWARD_PRECISION)
+            .map(|v| v / self.total_staked as u128)
+            .and_then(|v| v.checked_add(self.acc_reward_per_share))
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    pub fn get_skip_cooldown_amt(&self, left_cooldown: i64) -> Result<u64> {
+        let amt = (left_cooldown.max(0) as u64)
+            .checked_mul(self.skip_cooldown_rate)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    pub fn get_burn_amt(&self, gogr_amt: u64) -> Result<u64> {
+        let amt = gogr_amt
+            .checked_mul(self.burn_rate as u64)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?
+            / 100;
+        Ok(amt)
+    }
+
+    pub fn get_refund_amt(&self, gogr_amt: u64) -> Result<u64> {
+        let amt = gogr_amt
+            .checked_mul(self.reject_refund_rate as u64)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?
+            / 100;
+        Ok(amt)
+    }
 }
 
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct Pool {
     pub bump: u8,
     pub pool_id: u64,
+    pub launchpad: Pubkey,
     pub owner: Pubkey,
     // token info
     pub token_mint: Pubkey,
@@ -35,32 +174,259 @@ pub struct Pool {
     //game config
     pub gold_grid: u8,
     pub bomb_grid: u8,
-    pub cooldown_duration: u16,
+    // seconds
+    pub cooldown_duration: u32,
     pub allocation_value: u64,
     pub allocation_percentage: u64,
     pub vrf_count: u32,
     pub game_count: u64,
+    pub adjacency_rule: AdjacencyRule,
+    pub start_cell: StartCell,
+
+    // special cells
+    pub gem_grid: u8,
+    pub gem_multiplier: u8,
+    pub chest_grid: u8,
+    pub cave_in_grid: u8,
+    pub dud_grid: u8,
+    // seconds
+    pub dud_cooldown: u32,
+    // chest bonus sub-pool, kept in pool_token_account next to token_amount
+    pub bonus_token_amount: u64,
+    pub available_bonus_amount: u64,
+
+    pub status: PoolStatus,
+    pub season: u32,
+    // gogr held by the pool until the manager reviews it
+    pub gogr_escrow: u64,
+    // part of gogr_escrow that approve_pool turns into boost
+    pub boost_escrow: u64,
+
+    // boost from extra gogr, decays linearly to zero at boost_expires_at
+    pub boost_score: u64,
+    pub boost_updated_at: i64,
+    pub boost_expires_at: i64,
+
+    // secondary reward mints paid next to token_mint on gold
+    #[max_len(MAX_EXTRA_REWARDS)]
+    pub extra_rewards: Vec<ExtraReward>,
+
+    // native mint pools keep lamports in the sol vault instead of pool_token_account
+    pub sol_vault_bump: u8,
+
+    // nft cells hand out one of the escrowed nft_mints
+    pub nft_grid: u8,
+    // deposits must be verified members of this collection, default accepts any nft
+    pub nft_collection: Pubkey,
+    // only holders of this collection can play, default means no gate
+    pub gate_collection: Pubkey,
+    #[max_len(MAX_POOL_NFTS)]
+    pub nft_mints: Vec<Pubkey>,
+    // nft_mints plus the nfts reserved by running games, at most MAX_POOL_NFTS so
+    // returning reserved nfts on explosion always fits
+    pub nft_escrow_count: u8,
+
+    // collected pool tokens vest in seconds, nothing unlocks before the cliff
+    pub vesting_cliff: u32,
+    pub vesting_duration: u32,
+
+    // referrers earn referral_bps of each collected token_amount from this sub-pool
+    pub referral_bps: u16,
+    pub referral_token_amount: u64,
+    pub available_referral_amount: u64,
+
+    // bonus on a player's first collect of the utc day, plus streak_bonus_bps per
+    // extra streak day, in total at most MAX_DAILY_BONUS_BPS
+    pub daily_bonus_bps: u16,
+    pub streak_bonus_bps: u16,
+    // pool tokens paid as daily bonus per utc day over all players, 0 disables it
+    pub daily_bonus_budget: u64,
+    pub daily_bonus_day: i64,
+    pub daily_bonus_paid: u64,
+
+    pub cooldown_scope: CooldownScope,
+
+    // step 1 needs an ed25519 attestation from this key, default disables it
+    pub attestation_signer: Pubkey,
+
+    // step 1 wallet requirements, 0 disables each. wallet balances rely on
+    // utils::check_top_level keeping transfers out of the mine transaction,
+    // staked gogr can't be passed between wallets at all
+    pub min_gogr_balance: u64,
+    pub min_gogr_staked: u64,
+    pub min_token_balance: u64,
+    pub min_profile_age: u32,
+
+    // games started and not collected, exploded or expired yet, the owner can only
+    // close at 0
+    pub active_games: u32,
+
+    // the tournament running on this pool until tournament_end_ts, see
+    // utils::record_tournament_entry
+    pub tournament: Pubkey,
+    pub tournament_end_ts: i64,
 }
 
 impl Pool {
     pub fn total_grid(&self) -> u8 {
         X_GRID * Y_GRID
     }
-    pub fn get_reward_amt(&self) -> u64 {
-        if self.allocation_percentage > 0 {
-            let per = (self.available_token_amount as u128)
-                .checked_mul(self.allocation_percentage as u128)
-                .unwrap()
-                .checked_div(ALLOCATION_PERCENTAGE as u128)
-                .unwrap() as u64;
-            if per > self.allocation_value {
-                self.allocation_value
-            } else {
-                per
-            }
-        } else {
-            self.allocation_value
+
+    pub fn is_vesting(&self) -> bool {
+        self.vesting_duration > 0
+    }
+
+    pub fn is_sol(&self) -> bool {
+        self.token_mint == native_mint::ID
+    }
+
+    // a finished pool lets its mint start a new season
+    pub fn is_finished(&self) -> bool {
+        self.status == PoolStatus::Closed
+            || self.status == PoolStatus::Rejected
+            || self.available_token_amount == 0
+    }
+
+    pub fn start_game(&mut self) -> Result<()> {
+        self.active_games = self
+            .active_games
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    // saturating for games started before active_games was tracked
+    pub fn end_game(&mut self) {
+        self.active_games = self.active_games.saturating_sub(1);
+    }
+
+    pub fn get_boost_score(&self, cur: i64) -> u64 {
+        decayed_boost(
+            self.boost_score,
+            self.boost_updated_at,
+            self.boost_expires_at,
+            cur,
+        )
+    }
+
+    pub fn add_boost(&mut self, amount: u64, cur: i64) -> Result<()> {
+        self.boost_score = self
+            .get_boost_score(cur)
+            .checked_add(amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        self.boost_updated_at = cur;
+        self.boost_expires_at = cur
+            .checked_add(BOOST_DURATION)
+            .ok_or(LaunchpadErrorCode::TimestampOverflow)?;
+        Ok(())
+    }
+
+    pub fn special_grid(&self) -> u8 {
+        self.gold_grid
+            + self.bomb_grid
+            + self.gem_grid
+            + self.chest_grid
+            + self.cave_in_grid
+            + self.dud_grid
+            + self.nft_grid
+    }
+
+    // takes an escrowed nft out of the pool, None once they are all handed out
+    pub fn reserve_nft(&mut self, random: u64) -> Option<Pubkey> {
+        if self.nft_mints.is_empty() {
+            return None;
+        }
+        let i = (random % self.nft_mints.len() as u64) as usize;
+        Some(self.nft_mints.swap_remove(i))
+    }
+
+    // nfts that left the escrow for good
+    pub fn release_nfts(&mut self, count: usize) {
+        self.nft_escrow_count = self.nft_escrow_count.saturating_sub(count as u8);
+    }
+
+    // hands back every unreserved nft
+    pub fn take_nfts(&mut self) -> Vec<Pubkey> {
+        let nft_mints = std::mem::take(&mut self.nft_mints);
+        self.release_nfts(nft_mints.len());
+        nft_mints
+    }
+
+    // the pre-dug start cell can never be mined, so it is not an empty cell
+    pub fn empty_grid(&self) -> u8 {
+        self.total_grid()
+            .saturating_sub(1)
+            .saturating_sub(self.special_grid())
+    }
+
+    pub fn get_gem_amt(&self) -> Result<u64> {
+        let amt = self
+            .get_reward_amt()?
+            .checked_mul(self.gem_multiplier as u64)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    // chest bonus averages to an even share of the sub-pool over the chests left
+    pub fn get_chest_amt(&self, left_chest_grid: u8, random: u64) -> u64 {
+        if left_chest_grid == 0 {
+            return 0;
         }
+        let share = self.available_bonus_amount / left_chest_grid as u64;
+        let amt = random % (share.saturating_mul(2).saturating_add(1));
+        amt.min(self.available_bonus_amount)
+    }
+
+    pub fn get_referral_amt(&self, amt: u64) -> Result<u64> {
+        let referral_amt = (amt as u128)
+            .checked_mul(self.referral_bps as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(referral_amt.min(self.available_referral_amount))
+    }
+
+    pub fn get_daily_bonus_amt(&self, amt: u64, streak_days: u16) -> Result<u64> {
+        let streak_bps = (self.streak_bonus_bps as u64)
+            .checked_mul(streak_days.clamp(1, MAX_STREAK_DAYS) as u64 - 1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        let bps = (self.daily_bonus_bps as u64)
+            .checked_add(streak_bps)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?
+            .min(MAX_DAILY_BONUS_BPS as u64);
+        let bonus_amt = (amt as u128)
+            .checked_mul(bps as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(bonus_amt)
+    }
+
+    // caps a daily bonus by what is left of today's budget and books it, call
+    // with the amount the pool can still pay
+    pub fn take_daily_bonus(&mut self, amt: u64, cur: i64) -> Result<u64> {
+        let day = cur / SECONDS_PER_DAY;
+        if day != self.daily_bonus_day {
+            self.daily_bonus_day = day;
+            self.daily_bonus_paid = 0;
+        }
+        let amt = amt.min(
+            self.daily_bonus_budget
+                .saturating_sub(self.daily_bonus_paid),
+        );
+        self.daily_bonus_paid = self
+            .daily_bonus_paid
+            .checked_add(amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    pub fn get_reward_amt(&self) -> Result<u64> {
+        get_allocation_amt(
+            self.available_token_amount,
+            self.allocation_value,
+            self.allocation_percentage,
+        )
     }
 
     pub fn get_vrf(&mut self, cur: u64) -> u64 {
@@ -69,14 +435,134 @@ impl Pool {
         cur.hash(&mut hasher);
         self.vrf_count.hash(&mut hasher);
         self.available_token_amount.hash(&mut hasher);
-        let hash_result = hasher.finish();
-        hash_result as u64
+        hasher.finish()
+    }
+
+    // the random start cell is derived from public data so the frontend can show it before step 1
+    pub fn get_start_cell(&self, user: &Pubkey, game_counter: u32) -> (u8, u8) {
+        match self.start_cell {
+            StartCell::Origin => (0, 0),
+            StartCell::Fixed { x, y } => (x, y),
+            StartCell::Random => {
+                let hash = hashv(&[
+                    user.as_ref(),
+                    self.pool_id.to_be_bytes().as_ref(),
+                    game_counter.to_be_bytes().as_ref(),
+                ]);
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&hash.to_bytes()[..8]);
+                let n = (u64::from_be_bytes(bytes) % self.total_grid() as u64) as u8;
+                (n / Y_GRID, n % Y_GRID)
+            }
+        }
+    }
+}
+
+// registry of the pools a creator runs for a mint within a launchpad, one season at a time
+#[account]
+#[derive(InitSpace)]
+pub struct TokenExist {
+    pub bump: u8,
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub active_pool: Pubkey,
+    pub active_pool_id: u64,
+    pub season_count: u32,
+    // latest pool ids, oldest first
+    #[max_len(MAX_TOKEN_POOL_HISTORY)]
+    pub pool_ids: Vec<u64>,
+}
+
+impl TokenExist {
+    pub fn push_pool(&mut self, pool: Pubkey, pool_id: u64) -> Result<()> {
+        self.active_pool = pool;
+        self.active_pool_id = pool_id;
+        self.season_count = self
+            .season_count
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        if self.pool_ids.len() >= MAX_TOKEN_POOL_HISTORY {
+            self.pool_ids.remove(0);
+        }
+        self.pool_ids.push(pool_id);
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub struct ExtraReward {
+    pub mint: Pubkey,
+    pub token_amount: u64,
+    pub available_token_amount: u64,
+    pub allocation_value: u64,
+    pub allocation_percentage: u64,
+}
+
+impl ExtraReward {
+    pub fn get_reward_amt(&self) -> Result<u64> {
+        get_allocation_amt(
+            self.available_token_amount,
+            self.allocation_value,
+            self.allocation_percentage,
+        )
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub struct FeaturedPool {
+    pub pool: Pubkey,
+    pub pool_id: u64,
+    pub boost_score: u64,
+    pub boost_updated_at: i64,
+    pub boost_expires_at: i64,
+}
+
+impl FeaturedPool {
+    pub fn get_boost_score(&self, cur: i64) -> u64 {
+        decayed_boost(
+            self.boost_score,
+            self.boost_updated_at,
+            self.boost_expires_at,
+            cur,
+        )
+    }
+}
+
+// top boosted pools of a launchpad, highest score at the time of the last boost first
 #[account]
 #[derive(InitSpace)]
-pub struct TokenExist {}
+pub struct FeaturedPools {
+    pub bump: u8,
+    pub launchpad: Pubkey,
+    #[max_len(MAX_FEATURED_POOLS)]
+    pub pools: Vec<FeaturedPool>,
+}
+
+impl FeaturedPools {
+    pub fn init(&mut self, bump: u8, launchpad: Pubkey) {
+        if self.launchpad == Pubkey::default() {
+            self.bump = bump;
+            self.launchpad = launchpad;
+        }
+    }
+
+    pub fn update(&mut self, pool_key: Pubkey, pool: &Pool, cur: i64) {
+        self.pools
+            .retain(|p| p.pool != pool_key && p.get_boost_score(cur) > 0);
+        if pool.get_boost_score(cur) > 0 {
+            self.pools.push(FeaturedPool {
+                pool: pool_key,
+                pool_id: pool.pool_id,
+                boost_score: pool.boost_score,
+                boost_updated_at: pool.boost_updated_at,
+                boost_expires_at: pool.boost_expires_at,
+            });
+        }
+        self.pools
+            .sort_by_key(|p| std::cmp::Reverse(p.get_boost_score(cur)));
+        self.pools.truncate(MAX_FEATURED_POOLS);
+    }
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -85,8 +571,422 @@ pub struct UserTokenInfo {
     pub pool: Pubkey,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub total_amount: u64,
+    pub best_amount: u64,
+    pub best_step: u8,
+}
+
+// top players of a pool by collected pool tokens, highest first
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub reset_count: u32,
+    pub reset_at: i64,
+    #[max_len(MAX_LEADERBOARD_ENTRIES)]
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    // one insertion sort step, at most MAX_LEADERBOARD_ENTRIES swaps
+    pub fn update(&mut self, user: Pubkey, amount: u64, step: u8) -> Result<()> {
+        let i = match self.entries.iter().position(|e| e.user == user) {
+            Some(i) => i,
+            None => {
+                if self.entries.len() >= MAX_LEADERBOARD_ENTRIES {
+                    let last = self.entries.last().map_or(0, |e| e.total_amount);
+                    if amount <= last {
+                        return Ok(());
+                    }
+                    self.entries.pop();
+                }
+                self.entries.push(LeaderboardEntry {
+                    user,
+                    total_amount: 0,
+                    best_amount: 0,
+                    best_step: 0,
+                });
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[i];
+        entry.total_amount = entry
+            .total_amount
+            .checked_add(amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        entry.best_amount = entry.best_amount.max(amount);
+        entry.best_step = entry.best_step.max(step);
+
+        let mut i = i;
+        while i > 0 && self.entries[i - 1].total_amount < self.entries[i].total_amount {
+            self.entries.swap(i - 1, i);
+            i -= 1;
+        }
+        Ok(())
+    }
+
+    pub fn reset(&mut self, cur: i64) -> Result<()> {
+        self.entries.clear();
+        self.reset_count = self
+            .reset_count
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        self.reset_at = cur;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub struct TournamentStanding {
+    pub user: Pubkey,
+    pub score: u64,
+}
+
+// time-boxed competition over one or more pools of a launchpad sharing one token_mint
+#[account]
+#[derive(InitSpace)]
+pub struct Tournament {
+    pub bump: u8,
+    pub tournament_id: u64,
+    pub launchpad: Pubkey,
+    pub creator: Pubkey,
+    #[max_len(MAX_TOURNAMENT_POOLS)]
+    pub pools: Vec<Pubkey>,
+    // players enter until entry_end_ts, games count from start_ts to end_ts
+    pub start_ts: i64,
+    pub entry_end_ts: i64,
+    pub end_ts: i64,
+    pub scoring: TournamentScoring,
+    pub prize_mint: Pubkey,
+    pub prize_amount: u64,
+    // share of prize_amount in bps per rank, best first
+    #[max_len(MAX_TOURNAMENT_WINNERS)]
+    pub prize_shares: Vec<u16>,
+    pub entry_count: u32,
+    pub settled: bool,
+    // best scores first, one entry per rank
+    #[max_len(MAX_TOURNAMENT_WINNERS)]
+    pub standings: Vec<TournamentStanding>,
+}
+
+impl Tournament {
+    pub fn is_running(&self, pool: &Pubkey, cur: i64) -> bool {
+        !self.settled && cur >= self.start_ts && cur < self.end_ts && self.pools.contains(pool)
+    }
+
+    // keeps standings sorted with at most prize_shares.len() swaps, a score that
+    // drops stays listed until another player passes it
+    pub fn update_standings(&mut self, entry: &TournamentEntry) {
+        let user = entry.user;
+        let score = entry.get_score(self.scoring);
+        let i = match self.standings.iter().position(|s| s.user == user) {
+            Some(i) => {
+                self.standings[i].score = score;
+                i
+            }
+            None => {
+                if self.standings.len() >= self.prize_shares.len() {
+                    let last = self.standings.last().map_or(0, |s| s.score);
+                    if score <= last {
+                        return;
+                    }
+                    self.standings.pop();
+                }
+                self.standings.push(TournamentStanding { user, score });
+                self.standings.len() - 1
+            }
+        };
+        let mut i = i;
+        while i > 0 && self.standings[i - 1].score < self.standings[i].score {
+            self.standings.swap(i - 1, i);
+            i -= 1;
+        }
+        while i + 1 < self.standings.len() && self.standings[i + 1].score > self.standings[i].score
+        {
+            self.standings.swap(i, i + 1);
+            i += 1;
+        }
+    }
+
+    pub fn get_prize_amt(&self, rank: usize) -> Result<u64> {
+        let share = self.prize_shares.get(rank).copied().unwrap_or(0);
+        let amt = (self.prize_amount as u128)
+            .checked_mul(share as u128)
+            .and_then(|v| v.checked_div(BPS_DENOMINATOR as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+}
+
+// a player's results in a tournament
+#[account]
+#[derive(InitSpace)]
+pub struct TournamentEntry {
+    pub bump: u8,
+    pub tournament: Pubkey,
+    pub user: Pubkey,
+    pub total_collected: u64,
+    pub max_depth: u8,
+    pub collected_games: u32,
+    pub explosions: u32,
+}
+
+impl TournamentEntry {
+    // higher is better for every scoring rule
+    pub fn get_score(&self, scoring: TournamentScoring) -> u64 {
+        match scoring {
+            TournamentScoring::TotalCollected => self.total_collected,
+            TournamentScoring::MaxDepth => self.max_depth as u64,
+            // players need a collected game to rank, then fewer explosions win
+            TournamentScoring::FewestExplosions => {
+                if self.collected_games == 0 {
+                    0
+                } else {
+                    u32::MAX as u64 - self.explosions as u64
+                }
+            }
+        }
+    }
+
+    pub fn record_collect(&mut self, amount: u64, step: u8) -> Result<()> {
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        self.collected_games = self
+            .collected_games
+            .checked_add(1)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        self.max_depth = self.max_depth.max(step);
+        Ok(())
+    }
+
+    pub fn record_mine(&mut self, step: u8, step_status: StepStatus) -> Result<()> {
+        self.max_depth = self.max_depth.max(step);
+        if step_status == StepStatus::Exploded {
+            self.explosions = self
+                .explosions
+                .checked_add(1)
+                .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        }
+        Ok(())
+    }
+}
+
+// gogr a user keeps in the launchpad stake vault
+#[account]
+#[derive(InitSpace, Default)]
+pub struct UserStake {
+    pub bump: u8,
+    pub launchpad: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    // amount * acc_reward_per_share at the last settle, scaled by REWARD_PRECISION
+    pub reward_debt: u128,
+    pub pending_reward: u64,
+    // unstaked gogr waits unbonding_period, a new unstake restarts the wait
+    pub unbonding_amount: u64,
+    pub unbonding_end_ts: i64,
+}
+
+impl UserStake {
+    // scaled by REWARD_PRECISION, flooring only the settled difference keeps the
+    // sum of all payouts within the deposited rewards
+    fn get_accrued_reward(&self, acc_reward_per_share: u128) -> Result<u128> {
+        let reward = (self.amount as u128)
+            .checked_mul(acc_reward_per_share)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(reward)
+    }
+
+    // moves rewards earned so far into pending_reward, call before changing amount
+    pub fn settle_reward(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        let accrued = self
+            .get_accrued_reward(acc_reward_per_share)?
+            .checked_sub(self.reward_debt)
+            .map(|v| v / REWARD_PRECISION)
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        self.pending_reward = self
+            .pending_reward
+            .checked_add(accrued)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    // call after changing amount
+    pub fn reset_reward_debt(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        self.reward_debt = self.get_accrued_reward(acc_reward_per_share)?;
+        Ok(())
+    }
+}
+
+// one per user and launchpad
 #[account]
 #[derive(InitSpace)]
+pub struct UserProfile {
+    pub bump: u8,
+    pub launchpad: Pubkey,
+    pub user: Pubkey,
+    // set once, default means no referrer
+    pub referrer: Pubkey,
+    pub created_at: i64,
+    // consecutive utc days with a collected game
+    pub streak_days: u16,
+    pub last_active_day: i64,
+    // cross-pool cooldown from games in CooldownScope::Global pools
+    pub cooldown_timestamp: i64,
+    // game_info of the running game in a global pool, one at a time per wallet,
+    // released by collect, an explosion or expire_game
+    pub active_game: Pubkey,
+}
+
+impl UserProfile {
+    pub fn init(&mut self, bump: u8, launchpad: Pubkey, user: Pubkey, cur: i64) {
+        if self.user != Pubkey::default() {
+            return;
+        }
+        self.bump = bump;
+        self.launchpad = launchpad;
+        self.user = user;
+        self.created_at = cur;
+    }
+
+    pub fn start_game(&mut self, game_info: Pubkey, cur: i64) -> Result<()> {
+        require!(
+            self.cooldown_timestamp <= cur,
+            LaunchpadErrorCode::UserCoolDownPosErr
+        );
+        require!(
+            self.active_game == Pubkey::default(),
+            LaunchpadErrorCode::RunningGameErr
+        );
+        self.active_game = game_info;
+        Ok(())
+    }
+
+    pub fn end_game(&mut self, cooldown_timestamp: i64) {
+        self.cooldown_timestamp = self.cooldown_timestamp.max(cooldown_timestamp);
+        self.active_game = Pubkey::default();
+    }
+
+    // true on the first collect of the day
+    pub fn record_day(&mut self, cur: i64) -> bool {
+        let day = cur / SECONDS_PER_DAY;
+        if self.streak_days > 0 && day == self.last_active_day {
+            return false;
+        }
+        if self.streak_days > 0 && day == self.last_active_day + 1 {
+            self.streak_days = self.streak_days.saturating_add(1);
+        } else {
+            self.streak_days = 1;
+        }
+        self.last_active_day = day;
+        true
+    }
+}
+
+// referral earnings of a referrer in a pool, paid out by claim_referral
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub referrer: Pubkey,
+    pub total_amount: u64,
+    pub available_amount: u64,
+}
+
+// pool tokens collected by a user in a vesting pool, paid out by claim_vested
+#[account]
+#[derive(InitSpace, Default)]
+pub struct Vesting {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    // unlocked by earlier schedules and not claimed yet
+    pub released_amount: u64,
+    // current schedule, linear from start_ts to end_ts
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+impl Vesting {
+    pub fn get_unlocked_amt(&self, cur: i64) -> Result<u64> {
+        if cur < self.cliff_ts {
+            return Ok(0);
+        }
+        if cur >= self.end_ts {
+            return Ok(self.amount);
+        }
+        let amt = (self.amount as u128)
+            .checked_mul((cur - self.start_ts) as u128)
+            .and_then(|v| v.checked_div((self.end_ts - self.start_ts) as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    pub fn get_claimable_amt(&self, cur: i64) -> Result<u64> {
+        let amt = self
+            .get_unlocked_amt(cur)?
+            .checked_sub(self.claimed_amount)
+            .and_then(|v| v.checked_add(self.released_amount))
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(amt)
+    }
+
+    // the unlocked part moves to released_amount, the locked rest and amt vest
+    // together from cur, with cliff and end weighted by amount so earlier
+    // rewards are not pushed back to a full new schedule
+    pub fn add_amount(&mut self, pool: &Pool, amt: u64, cur: i64) -> Result<()> {
+        let unlocked = self.get_unlocked_amt(cur)?;
+        self.released_amount = self.get_claimable_amt(cur)?;
+        let locked = self
+            .amount
+            .checked_sub(unlocked)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        let amount = locked
+            .checked_add(amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        let weighted_ts = |ts: i64, duration: u32| -> Result<i64> {
+            if amount == 0 {
+                return Ok(cur);
+            }
+            let left = ts.saturating_sub(cur).max(0) as u128;
+            let left = (locked as u128 * left + amt as u128 * duration as u128) / amount as u128;
+            let ts = i64::try_from(left)
+                .ok()
+                .and_then(|v| cur.checked_add(v))
+                .ok_or(LaunchpadErrorCode::TimestampOverflow)?;
+            Ok(ts)
+        };
+        self.cliff_ts = weighted_ts(self.cliff_ts, pool.vesting_cliff)?;
+        self.end_ts = weighted_ts(self.end_ts, pool.vesting_duration)?;
+        self.start_ts = cur;
+        self.amount = amount;
+        self.claimed_amount = 0;
+        Ok(())
+    }
+
+    pub fn claim(&mut self, cur: i64) -> Result<u64> {
+        let amt = self.get_claimable_amt(cur)?;
+        self.claimed_amount = self.get_unlocked_amt(cur)?;
+        self.released_amount = 0;
+        Ok(amt)
+    }
+}
+
+#[account]
+#[derive(InitSpace, Default)]
 pub struct GameInfo {
     pub bump: u8,
     pub user: Pubkey,
@@ -101,37 +1001,148 @@ pub struct GameInfo {
     pub grid_info: u128,
     pub grid_gold_info: u128,
     pub exploded_grid: u8,
-    pub cooldown_timestamp: u32,
+    pub cooldown_timestamp: i64,
+    pub start_x: u8,
+    pub start_y: u8,
+    pub mined_gem_grid: u8,
+    pub mined_chest_grid: u8,
+    pub mined_cave_in_grid: u8,
+    pub mined_dud_grid: u8,
+    pub bonus_amount: u64,
+    // extra cooldown in seconds picked up from duds, applied when the game ends
+    pub extra_cooldown: u32,
+    pub last_step_status: StepStatus,
+    // accrued per pool.extra_rewards entry
+    #[max_len(MAX_EXTRA_REWARDS)]
+    pub extra_token_amounts: Vec<u64>,
+    pub mined_nft_grid: u8,
+    // nfts reserved for this game, paid on collect and returned to the pool on explosion
+    #[max_len(MAX_POOL_NFTS)]
+    pub nft_rewards: Vec<Pubkey>,
+    // from the user's stake tier when the game ends
+    pub cooldown_reduction_bps: u16,
+    // a running game can be expired GAME_TIMEOUT seconds after its last step
+    pub last_step_at: i64,
 }
 
 impl GameInfo {
-    pub fn handle_vrf(&mut self, pool: &Pool, random: u64) -> Result<StepStatus> {
-        let total_grid = pool.total_grid() as u16;
-        let left_all_grid = total_grid - self.mined_empty_grid as u16 - self.mined_gold_grid as u16;
-        let left_gold_grid = (pool.gold_grid - self.mined_gold_grid) as u16;
-        let left_empty_grid = total_grid
-            - pool.gold_grid as u16
-            - pool.bomb_grid as u16
-            - self.mined_empty_grid as u16;
-        let remainder = (random % left_all_grid as u64) as u16;
-        let step_statsu = if remainder <= left_empty_grid {
-            self.game_status = GameStatus::Working;
-            self.mined_empty_grid += 1;
-            StepStatus::Empty
-        } else if remainder <= left_empty_grid + left_gold_grid {
-            let amt = pool.get_reward_amt();
-            self.token_amount += amt;
-            self.game_status = GameStatus::Working;
-            self.mined_gold_grid += 1;
-            StepStatus::Gold
-        } else {
-            self.game_status = GameStatus::Exploded;
-            let clock = Clock::get()?;
-            let cur = clock.unix_timestamp;
-            self.cooldown_timestamp = cur as u32 + (pool.cooldown_duration as u32) * 60;
-            StepStatus::Exploded
+    // remaining unmined cells per outcome, in draw order
+    pub fn left_grid(&self, pool: &Pool) -> Result<[(StepStatus, u8); 8]> {
+        let left = |total: u8, mined: u8| -> Result<u8> {
+            total
+                .checked_sub(mined)
+                .ok_or(error!(LaunchpadErrorCode::GameGridErr))
         };
-        Ok(step_statsu)
+        Ok([
+            (
+                StepStatus::Empty,
+                left(pool.empty_grid(), self.mined_empty_grid)?,
+            ),
+            (
+                StepStatus::Gold,
+                left(pool.gold_grid, self.mined_gold_grid)?,
+            ),
+            (StepStatus::Gem, left(pool.gem_grid, self.mined_gem_grid)?),
+            (
+                StepStatus::Chest,
+                left(pool.chest_grid, self.mined_chest_grid)?,
+            ),
+            (
+                StepStatus::CaveIn,
+                left(pool.cave_in_grid, self.mined_cave_in_grid)?,
+            ),
+            (StepStatus::Dud, left(pool.dud_grid, self.mined_dud_grid)?),
+            (StepStatus::Nft, left(pool.nft_grid, self.mined_nft_grid)?),
+            (StepStatus::Exploded, pool.bomb_grid),
+        ])
+    }
+
+    pub fn handle_vrf(&mut self, pool: &Pool, random: u64, cur: i64) -> Result<StepStatus> {
+        let left = self.left_grid(pool)?;
+        let step_status = draw_step_status(&left, random).ok_or(LaunchpadErrorCode::GameGridErr)?;
+        let left_all_grid = left_all_grid(&left);
+
+        self.game_status = GameStatus::Working;
+        match step_status {
+            StepStatus::Empty => {
+                self.mined_empty_grid += 1;
+            }
+            StepStatus::Gold => {
+                self.add_token_amount(pool.get_reward_amt()?)?;
+                self.add_extra_token_amounts(pool, 1)?;
+                self.mined_gold_grid += 1;
+            }
+            StepStatus::Gem => {
+                self.add_token_amount(pool.get_gem_amt()?)?;
+                self.add_extra_token_amounts(pool, pool.gem_multiplier as u64)?;
+                self.mined_gem_grid += 1;
+            }
+            StepStatus::Chest => {
+                let left_chest_grid = pool.chest_grid - self.mined_chest_grid;
+                let bonus = pool.get_chest_amt(left_chest_grid, random / left_all_grid);
+                self.bonus_amount = self
+                    .bonus_amount
+                    .checked_add(bonus)
+                    .ok_or(LaunchpadErrorCode::MathOverflow)?;
+                self.mined_chest_grid += 1;
+            }
+            StepStatus::CaveIn => {
+                self.token_amount /= 2;
+                self.extra_token_amounts
+                    .iter_mut()
+                    .for_each(|amt| *amt /= 2);
+                self.mined_cave_in_grid += 1;
+            }
+            StepStatus::Dud => {
+                self.extra_cooldown = pool
+                    .dud_cooldown
+                    .checked_add(self.extra_cooldown)
+                    .ok_or(LaunchpadErrorCode::TimestampOverflow)?;
+                self.mined_dud_grid += 1;
+            }
+            StepStatus::Nft => {
+                self.mined_nft_grid += 1;
+            }
+            StepStatus::Exploded => {
+                self.game_status = GameStatus::Exploded;
+                self.set_cooldown(pool, cur)?;
+            }
+        }
+        self.last_step_status = step_status;
+        Ok(step_status)
+    }
+
+    pub fn add_token_amount(&mut self, amt: u64) -> Result<()> {
+        self.token_amount = self
+            .token_amount
+            .checked_add(amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn add_extra_token_amounts(&mut self, pool: &Pool, multiplier: u64) -> Result<()> {
+        self.extra_token_amounts.resize(pool.extra_rewards.len(), 0);
+        for (amt, reward) in self
+            .extra_token_amounts
+            .iter_mut()
+            .zip(pool.extra_rewards.iter())
+        {
+            *amt = reward
+                .get_reward_amt()?
+                .checked_mul(multiplier)
+                .and_then(|v| v.checked_add(*amt))
+                .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    pub fn set_cooldown(&mut self, pool: &Pool, cur: i64) -> Result<()> {
+        let cooldown = pool.cooldown_duration as i64 + self.extra_cooldown as i64;
+        let reduction = cooldown * self.cooldown_reduction_bps as i64 / BPS_DENOMINATOR as i64;
+        self.cooldown_timestamp = cur
+            .checked_add(cooldown - reduction)
+            .ok_or(LaunchpadErrorCode::TimestampOverflow)?;
+        Ok(())
     }
 
     pub fn clear_data(&mut self) {
@@ -144,67 +1155,248 @@ impl GameInfo {
         self.cooldown_timestamp = 0;
         self.grid_gold_info = 0;
         self.exploded_grid = 0;
+        self.start_x = 0;
+        self.start_y = 0;
+        self.mined_gem_grid = 0;
+        self.mined_chest_grid = 0;
+        self.mined_cave_in_grid = 0;
+        self.mined_dud_grid = 0;
+        self.bonus_amount = 0;
+        self.extra_cooldown = 0;
+        self.last_step_status = StepStatus::Empty;
+        self.extra_token_amounts.clear();
+        self.mined_nft_grid = 0;
+        self.nft_rewards.clear();
+        self.cooldown_reduction_bps = 0;
+        self.last_step_at = 0;
+    }
+
+    pub fn is_expired(&self, cur: i64) -> bool {
+        cur >= self.last_step_at.saturating_add(GAME_TIMEOUT)
+    }
+
+    pub fn set_start_cell(&mut self, pool: &Pool) {
+        let (x, y) = pool.get_start_cell(&self.user, self.game_counter);
+        self.start_x = x;
+        self.start_y = y;
     }
 
     pub fn get_grid_mined(&self, x: u8, y: u8) -> bool {
-        if x == 0 && y == 0 {
+        if x == self.start_x && y == self.start_y {
             return true;
         }
-        let n = 10 * x + y;
-        return (self.grid_info & (1 << n)) != 0;
+        let n = grid_index(x, y);
+        (self.grid_info & (1 << n)) != 0
     }
 
     pub fn set_grid_mined(&mut self, x: u8, y: u8, step_status: StepStatus) {
-        let n = 10 * x + y;
-        self.grid_info = self.grid_info | 1 << n;
-        if step_status == StepStatus::Gold {
-            self.grid_gold_info = self.grid_gold_info | 1 << n;
+        let n = grid_index(x, y);
+        self.grid_info |= 1 << n;
+        if step_status == StepStatus::Gold
+            || step_status == StepStatus::Gem
+            || step_status == StepStatus::Nft
+        {
+            self.grid_gold_info |= 1 << n;
         } else if step_status == StepStatus::Exploded {
             self.exploded_grid = n
         }
     }
 
-    pub fn check_grid(&self, x: u8, y: u8) -> Result<()> {
+    fn neighbour_mined(&self, x: u8, y: u8, dx: i8, dy: i8) -> bool {
+        let nx = x as i16 + dx as i16;
+        let ny = y as i16 + dy as i16;
+        if nx < 0 || ny < 0 || nx >= X_GRID as i16 || ny >= Y_GRID as i16 {
+            return false;
+        }
+        self.get_grid_mined(nx as u8, ny as u8)
+    }
+
+    pub fn check_grid(&self, pool: &Pool, x: u8, y: u8) -> Result<()> {
+        require!(x < X_GRID && y < Y_GRID, LaunchpadErrorCode::GameStepPosErr);
         require!(
             !self.get_grid_mined(x, y),
             LaunchpadErrorCode::GameStepPosErr
         );
-        let left_mined = x > 0 && self.get_grid_mined(x - 1, y);
-        let right_mined = x < X_GRID - 1 && self.get_grid_mined(x + 1, y);
-        let up_mined = y > 0 && self.get_grid_mined(x, y - 1);
-        let down_mined = y < Y_GRID - 1 && self.get_grid_mined(x, y + 1);
+        let neighbour_mined = match pool.adjacency_rule {
+            AdjacencyRule::Free => true,
+            AdjacencyRule::Orthogonal => {
+                self.neighbour_mined(x, y, -1, 0)
+                    || self.neighbour_mined(x, y, 1, 0)
+                    || self.neighbour_mined(x, y, 0, -1)
+                    || self.neighbour_mined(x, y, 0, 1)
+            }
+            AdjacencyRule::Diagonal => (-1..=1i8).any(|dx| {
+                (-1..=1i8).any(|dy| (dx != 0 || dy != 0) && self.neighbour_mined(x, y, dx, dy))
+            }),
+        };
 
-        require!(
-            left_mined || right_mined || up_mined || down_mined,
-            LaunchpadErrorCode::GameStepPosErr
-        );
+        require!(neighbour_mined, LaunchpadErrorCode::GameStepPosErr);
         Ok(())
     }
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+pub fn grid_index(x: u8, y: u8) -> u8 {
+    Y_GRID * x + y
+}
+
+pub fn get_allocation_amt(
+    available_token_amount: u64,
+    allocation_value: u64,
+    allocation_percentage: u64,
+) -> Result<u64> {
+    if allocation_percentage > 0 {
+        let per = (available_token_amount as u128)
+            .checked_mul(allocation_percentage as u128)
+            .and_then(|v| v.checked_div(ALLOCATION_PERCENTAGE as u128))
+            .and_then(|v| u64::try_from(v).ok())
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        if per > allocation_value {
+            Ok(allocation_value)
+        } else {
+            Ok(per)
+        }
+    } else {
+        Ok(allocation_value)
+    }
+}
+
+pub fn decayed_boost(score: u64, updated_at: i64, expires_at: i64, cur: i64) -> u64 {
+    if cur >= expires_at || expires_at <= updated_at {
+        return 0;
+    }
+    let left = (expires_at - cur.max(updated_at)) as u128;
+    let total = (expires_at - updated_at) as u128;
+    (score as u128 * left / total) as u64
+}
+
+pub fn left_all_grid(left: &[(StepStatus, u8)]) -> u64 {
+    left.iter().map(|(_, n)| *n as u64).sum()
+}
+
+// Weighted draw over the remaining cells: residue r of random % total picks the
+// outcome whose cumulative range [start, start + n) contains r, so each outcome
+// has exactly n / total of the residues. The only deviation from n / total is
+// the u64 modulo bias, below total / 2^64. None when no cell is left.
+pub fn draw_step_status(left: &[(StepStatus, u8)], random: u64) -> Option<StepStatus> {
+    let total = left_all_grid(left);
+    if total == 0 {
+        return None;
+    }
+    let mut remainder = random % total;
+    for (status, n) in left {
+        if remainder < *n as u64 {
+            return Some(*status);
+        }
+        remainder -= *n as u64;
+    }
+    None
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
 pub enum GameStatus {
+    #[default]
     None,
     Working,
     Collected,
     Exploded,
+    // forfeited by the player or abandoned, see expire_game
+    Expired,
 }
 
-#[derive(Eq, PartialEq, Copy, Clone)]
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
 pub enum StepStatus {
+    #[default]
     Empty,
     Exploded,
     Gold,
+    // gold times pool.gem_multiplier
+    Gem,
+    // random bonus from the pool bonus sub-pool
+    Chest,
+    // lose half of the game token_amount
+    CaveIn,
+    // bomb that only adds pool.dud_cooldown seconds
+    Dud,
+    // one nft from the pool escrow, empty once the escrow runs out
+    Nft,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace)]
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
 pub enum AllocationType {
+    #[default]
     Percentages,
     FixedAmount,
 }
 
-impl Default for AllocationType {
-    fn default() -> Self {
-        AllocationType::Percentages
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
+pub enum PoolStatus {
+    #[default]
+    Active,
+    Closed,
+    PendingReview,
+    Rejected,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
+pub enum TournamentScoring {
+    #[default]
+    TotalCollected,
+    MaxDepth,
+    FewestExplosions,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
+pub enum CooldownScope {
+    // a game only cools down its own pool
+    #[default]
+    Pool,
+    // a game cools down every global pool of the launchpad, see UserProfile
+    Global,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
+pub enum AdjacencyRule {
+    // 4 neighbours
+    #[default]
+    Orthogonal,
+    // 8 neighbours
+    Diagonal,
+    // any unmined cell
+    Free,
+}
+
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Eq, PartialEq, Clone, Copy, Debug, InitSpace, Default,
+)]
+pub enum StartCell {
+    #[default]
+    Origin,
+    Random,
+    Fixed {
+        x: u8,
+        y: u8,
+    },
+}
+
+impl StartCell {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            StartCell::Fixed { x, y } => *x < X_GRID && *y < Y_GRID,
+            _ => true,
+        }
     }
 }
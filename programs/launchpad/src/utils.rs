@@ -0,0 +1,384 @@
+use crate::errors::LaunchpadErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT},
+    pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_lang::system_program;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{burn, transfer, Burn, Mint, Token, TokenAccount, Transfer},
+};
+
+use anchor_spl::metadata::{mpl_token_metadata, MetadataAccount};
+
+use crate::seeds::*;
+use crate::state::*;
+
+// remaining accounts come in (pool_reward_account, recipient_reward_account) pairs,
+// one pair per (mint, amount) entry: pool.extra_rewards first, then nfts
+pub fn transfer_pool_rewards<'info>(
+    pool: &Account<'info, Pool>,
+    token_program: &Program<'info, Token>,
+    remaining_accounts: &[AccountInfo<'info>],
+    recipient: Pubkey,
+    rewards: &[(Pubkey, u64)],
+) -> Result<()> {
+    for (i, (mint, amount)) in rewards.iter().enumerate() {
+        if *amount == 0 {
+            continue;
+        }
+        let from = remaining_accounts
+            .get(2 * i)
+            .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+        let to = remaining_accounts
+            .get(2 * i + 1)
+            .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+        require_keys_eq!(
+            from.key(),
+            get_associated_token_address(&pool.key(), mint),
+            LaunchpadErrorCode::RewardAccountErr
+        );
+        require_keys_eq!(
+            *to.owner,
+            token_program.key(),
+            LaunchpadErrorCode::RewardAccountErr
+        );
+        let to_account = TokenAccount::try_deserialize(&mut &to.data.borrow()[..])?;
+        require!(
+            to_account.mint == *mint && to_account.owner == recipient,
+            LaunchpadErrorCode::RewardAccountErr
+        );
+        transfer_from_pool(pool, token_program, from.clone(), to.clone(), *amount)?;
+    }
+    Ok(())
+}
+
+// token transfer out of an account owned by the pool, signed by the pool pda
+pub fn transfer_from_pool<'info>(
+    pool: &Account<'info, Pool>,
+    token_program: &Program<'info, Token>,
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let pool_id_bytes = pool.pool_id.to_be_bytes();
+    let signer_seeds = [
+        POOL_INFO_SEED,
+        pool.launchpad.as_ref(),
+        pool_id_bytes.as_ref(),
+        &[pool.bump],
+    ];
+    transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                to,
+                from,
+                authority: pool.to_account_info(),
+            },
+        )
+        .with_signer(&[&signer_seeds]),
+        amount,
+    )
+}
+
+// the vault is a system account, so it keeps the rent-exempt minimum on top of the pool amounts
+pub fn deposit_sol<'info>(
+    from: &Signer<'info>,
+    sol_vault: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let mut lamports = amount;
+    if sol_vault.lamports() == 0 {
+        lamports = Rent::get()?
+            .minimum_balance(0)
+            .checked_add(amount)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    }
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: from.to_account_info(),
+                to: sol_vault.to_account_info(),
+            },
+        ),
+        lamports,
+    )
+}
+
+pub fn withdraw_sol<'info>(
+    pool: &Account<'info, Pool>,
+    sol_vault: &SystemAccount<'info>,
+    to: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    let pool_key = pool.key();
+    let signer_seeds = [SOL_VAULT_SEED, pool_key.as_ref(), &[pool.sol_vault_bump]];
+    system_program::transfer(
+        CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: sol_vault.to_account_info(),
+                to,
+            },
+        )
+        .with_signer(&[&signer_seeds]),
+        amount,
+    )
+}
+
+// pays pool tokens to recipient, from the sol vault in sol pools and from
+// pool_token_account into token_owner_account otherwise
+#[allow(clippy::too_many_arguments)]
+pub fn withdraw_pool_token<'info>(
+    pool: &Account<'info, Pool>,
+    pool_token_account: &Option<Box<Account<'info, TokenAccount>>>,
+    token_owner_account: &Option<Box<Account<'info, TokenAccount>>>,
+    sol_vault: &Option<SystemAccount<'info>>,
+    recipient: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    if pool.is_sol() {
+        let sol_vault = sol_vault
+            .as_ref()
+            .ok_or(LaunchpadErrorCode::RewardAccountErr)?;
+        return withdraw_sol(pool, sol_vault, recipient, system_program, amount);
+    }
+    let (Some(pool_token_account), Some(token_owner_account)) =
+        (pool_token_account, token_owner_account)
+    else {
+        return err!(LaunchpadErrorCode::RewardAccountErr);
+    };
+    transfer_from_pool(
+        pool,
+        token_program,
+        pool_token_account.to_account_info(),
+        token_owner_account.to_account_info(),
+        amount,
+    )
+}
+
+// gogr a signer pays to the launchpad: burn_rate of it is burned, the stakers'
+// share goes to the stake vault and the rest to the receiver
+pub struct GogrPayment<'a, 'info> {
+    pub signer: &'a Signer<'info>,
+    pub gogr_mint: &'a Account<'info, Mint>,
+    pub gogr_from_account: &'a Account<'info, TokenAccount>,
+    pub gogr_receiver_account: &'a Account<'info, TokenAccount>,
+    // required while gogr is staked, see Launchpad::add_staking_reward
+    pub stake_vault: Option<&'a Account<'info, TokenAccount>>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'info> GogrPayment<'_, 'info> {
+    fn transfer_to(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Transfer {
+                    to,
+                    from: self.gogr_from_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    pub fn pay(&self, launchpad: &mut Launchpad, amount: u64) -> Result<()> {
+        let burn_amt = launchpad.get_burn_amt(amount)?;
+        let left_amt = amount
+            .checked_sub(burn_amt)
+            .ok_or(LaunchpadErrorCode::MathOverflow)?;
+        let staking_amt = launchpad.add_staking_reward(left_amt)?;
+        self.transfer_to(
+            self.gogr_receiver_account.to_account_info(),
+            left_amt - staking_amt,
+        )?;
+        if staking_amt > 0 {
+            let stake_vault = self.stake_vault.ok_or(LaunchpadErrorCode::StakeErr)?;
+            self.transfer_to(stake_vault.to_account_info(), staking_amt)?;
+        }
+        burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                Burn {
+                    mint: self.gogr_mint.to_account_info(),
+                    from: self.gogr_from_account.to_account_info(),
+                    authority: self.signer.to_account_info(),
+                },
+            ),
+            burn_amt,
+        )
+    }
+}
+
+// every gogr boost is paid like a fee and moves the pool in featured_pools right away
+pub fn add_pool_boost<'info>(
+    pool: &mut Account<'info, Pool>,
+    featured_pools: &mut FeaturedPools,
+    launchpad: &mut Launchpad,
+    payment: &GogrPayment<'_, 'info>,
+    amount: u64,
+    cur: i64,
+) -> Result<()> {
+    pool.gogr_ext = pool
+        .gogr_ext
+        .checked_add(amount)
+        .ok_or(LaunchpadErrorCode::MathOverflow)?;
+    pool.add_boost(amount, cur)?;
+    featured_pools.update(pool.key(), pool, cur);
+    payment.pay(launchpad, amount)
+}
+
+// the metadata must be the mint's metaplex pda and a verified member of collection
+pub fn check_collection(
+    metadata: &Account<MetadataAccount>,
+    mint: Pubkey,
+    collection: Pubkey,
+) -> Result<()> {
+    let (metadata_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    require_keys_eq!(
+        metadata.key(),
+        metadata_key,
+        LaunchpadErrorCode::NftCollectionErr
+    );
+    let verified = metadata
+        .collection
+        .as_ref()
+        .is_some_and(|c| c.verified && c.key == collection);
+    require!(verified, LaunchpadErrorCode::NftCollectionErr);
+    Ok(())
+}
+
+// The instruction right before the current one must be an ed25519 program
+// instruction with one signature by attestation_signer over
+// pool (32) | user (32) | expiry (i64 le), all data inline.
+pub fn check_attestation(
+    instructions_sysvar: &AccountInfo,
+    attestation_signer: &Pubkey,
+    pool: &Pubkey,
+    user: &Pubkey,
+    cur: i64,
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, LaunchpadErrorCode::AttestationErr);
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        LaunchpadErrorCode::AttestationErr
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= 16 && data[0] == 1,
+        LaunchpadErrorCode::AttestationErr
+    );
+    let read_u16 = |i: usize| u16::from_le_bytes([data[i], data[i + 1]]);
+    let public_key_offset = read_u16(6) as usize;
+    let message_offset = read_u16(10) as usize;
+    let message_size = read_u16(12) as usize;
+    require!(
+        read_u16(4) == u16::MAX && read_u16(8) == u16::MAX && read_u16(14) == u16::MAX,
+        LaunchpadErrorCode::AttestationErr
+    );
+
+    let public_key = data
+        .get(public_key_offset..public_key_offset + 32)
+        .ok_or(LaunchpadErrorCode::AttestationErr)?;
+    require!(
+        public_key == attestation_signer.as_ref(),
+        LaunchpadErrorCode::AttestationErr
+    );
+
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(LaunchpadErrorCode::AttestationErr)?;
+    require!(message_size == 72, LaunchpadErrorCode::AttestationErr);
+    require!(
+        &message[..32] == pool.as_ref() && &message[32..64] == user.as_ref(),
+        LaunchpadErrorCode::AttestationErr
+    );
+    let mut expiry = [0u8; 8];
+    expiry.copy_from_slice(&message[64..72]);
+    require!(
+        i64::from_le_bytes(expiry) >= cur,
+        LaunchpadErrorCode::AttestationErr
+    );
+    Ok(())
+}
+
+const COMPUTE_BUDGET_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+// Outcomes are known inside the transaction, so a caller program or any other
+// instruction that reads game_info afterwards could revert on a loss.
+pub fn check_top_level(instructions_sysvar: &AccountInfo) -> Result<()> {
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+        LaunchpadErrorCode::TopLevelErr
+    );
+    check_sibling_instructions(instructions_sysvar)
+}
+
+// Besides the current launchpad instruction only compute budget instructions
+// and the ed25519 attestation right before it are allowed in the transaction.
+pub fn check_sibling_instructions(instructions_sysvar: &AccountInfo) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        let allowed = if index == current_index {
+            ix.program_id == crate::ID
+        } else {
+            ix.program_id == COMPUTE_BUDGET_ID
+                || (index + 1 == current_index && ix.program_id == ed25519_program::ID)
+        };
+        require!(allowed, LaunchpadErrorCode::TopLevelErr);
+        index += 1;
+    }
+    Ok(())
+}
+
+// A tournament running on the pool records every game of its entrants, so the
+// entry of (pool.tournament, signer) has to be passed whether the game is won
+// or lost. Signers that never entered pass the empty entry address.
+pub fn record_tournament_entry<'info>(
+    pool: &Account<'info, Pool>,
+    tournament: &mut Option<Box<Account<'info, Tournament>>>,
+    tournament_entry: &Option<UncheckedAccount<'info>>,
+    cur: i64,
+    record: impl FnOnce(&mut TournamentEntry) -> Result<()>,
+) -> Result<()> {
+    if pool.tournament == Pubkey::default() || cur >= pool.tournament_end_ts {
+        return Ok(());
+    }
+    let tournament = tournament
+        .as_mut()
+        .ok_or(LaunchpadErrorCode::TournamentErr)?;
+    if !tournament.is_running(&pool.key(), cur) {
+        return Ok(());
+    }
+    let tournament_entry = tournament_entry
+        .as_ref()
+        .ok_or(LaunchpadErrorCode::TournamentErr)?;
+    if tournament_entry.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(
+        *tournament_entry.owner,
+        crate::ID,
+        LaunchpadErrorCode::TournamentErr
+    );
+    let mut entry = TournamentEntry::try_deserialize(&mut &tournament_entry.data.borrow()[..])?;
+    record(&mut entry)?;
+    tournament.update_standings(&entry);
+    entry.try_serialize(&mut &mut tournament_entry.data.borrow_mut()[..])
+}
@@ -0,0 +1,135 @@
+use launchpad::state::*;
+use proptest::prelude::*;
+
+const USERS: usize = 3;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Stake(usize, u64),
+    Unstake(usize, u64),
+    Claim(usize),
+    Fee(u64),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..USERS, 1..1_000_000_000u64).prop_map(|(u, amt)| Op::Stake(u, amt)),
+        (0..USERS, 1..1_000_000_000u64).prop_map(|(u, amt)| Op::Unstake(u, amt)),
+        (0..USERS).prop_map(Op::Claim),
+        (0..1_000_000_000u64).prop_map(Op::Fee),
+    ]
+}
+
+// the same steps as stake_gogr, unstake_gogr and claim_staking_rewards
+struct Staking {
+    launchpad: Launchpad,
+    stakes: Vec<UserStake>,
+    deposited: u64,
+    claimed: u64,
+}
+
+impl Staking {
+    fn new(staking_fee_rate: u8) -> Self {
+        Staking {
+            launchpad: Launchpad {
+                staking_fee_rate,
+                ..Default::default()
+            },
+            stakes: (0..USERS).map(|_| UserStake::default()).collect(),
+            deposited: 0,
+            claimed: 0,
+        }
+    }
+
+    fn change_stake(&mut self, user: usize, amt: u64, stake: bool) {
+        let acc = self.launchpad.acc_reward_per_share;
+        let user_stake = &mut self.stakes[user];
+        user_stake.settle_reward(acc).unwrap();
+        if stake {
+            user_stake.amount += amt;
+            self.launchpad.total_staked += amt;
+        } else {
+            user_stake.amount -= amt;
+            self.launchpad.total_staked -= amt;
+        }
+        user_stake.reset_reward_debt(acc).unwrap();
+    }
+
+    fn claim(&mut self, user: usize) -> u64 {
+        let acc = self.launchpad.acc_reward_per_share;
+        let user_stake = &mut self.stakes[user];
+        user_stake.settle_reward(acc).unwrap();
+        user_stake.reset_reward_debt(acc).unwrap();
+        let amt = std::mem::take(&mut user_stake.pending_reward);
+        self.claimed += amt;
+        amt
+    }
+
+    fn apply(&mut self, op: Op) {
+        match op {
+            Op::Stake(user, amt) => self.change_stake(user, amt, true),
+            Op::Unstake(user, amt) => {
+                let amt = amt.min(self.stakes[user].amount);
+                self.change_stake(user, amt, false);
+            }
+            Op::Claim(user) => {
+                self.claim(user);
+            }
+            Op::Fee(fee) => self.deposited += self.launchpad.add_staking_reward(fee).unwrap(),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn claims_never_exceed_deposits(
+        staking_fee_rate in 0u8..=100,
+        ops in prop::collection::vec(op(), 1..100),
+    ) {
+        let mut staking = Staking::new(staking_fee_rate);
+        let n = ops.len() as u64;
+        for op in ops {
+            staking.apply(op);
+            prop_assert!(staking.claimed <= staking.deposited);
+        }
+        for user in 0..USERS {
+            staking.claim(user);
+        }
+        prop_assert!(staking.claimed <= staking.deposited);
+        // flooring loses at most one unit per fee and per settle
+        prop_assert!(staking.deposited - staking.claimed <= 2 * n + USERS as u64);
+    }
+}
+
+#[test]
+fn fee_without_stakers_is_not_taken() {
+    let mut staking = Staking::new(50);
+    assert_eq!(staking.launchpad.add_staking_reward(1_000).unwrap(), 0);
+    assert_eq!(staking.launchpad.acc_reward_per_share, 0);
+}
+
+#[test]
+fn only_stakers_at_fee_time_earn_it() {
+    let mut staking = Staking::new(50);
+    staking.apply(Op::Stake(0, 100));
+    staking.apply(Op::Fee(1_000));
+    staking.apply(Op::Stake(1, 400));
+    staking.apply(Op::Fee(1_000));
+    staking.apply(Op::Unstake(0, 100));
+    staking.apply(Op::Fee(1_000));
+    assert_eq!(staking.claim(0), 500 + 100);
+    assert_eq!(staking.claim(1), 400 + 500);
+    assert_eq!(staking.claimed, staking.deposited);
+}
+
+#[test]
+fn rounding_rounds_down() {
+    let mut staking = Staking::new(100);
+    staking.apply(Op::Stake(0, 1));
+    staking.apply(Op::Stake(1, 1));
+    staking.apply(Op::Stake(2, 1));
+    staking.apply(Op::Fee(10));
+    let paid: u64 = (0..USERS).map(|user| staking.claim(user)).sum();
+    assert_eq!(paid, 9);
+    assert_eq!(staking.deposited, 10);
+}
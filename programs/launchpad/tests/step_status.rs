@@ -0,0 +1,95 @@
+use launchpad::state::*;
+use proptest::prelude::*;
+
+const OUTCOMES: [StepStatus; 8] = [
+    StepStatus::Empty,
+    StepStatus::Gold,
+    StepStatus::Gem,
+    StepStatus::Chest,
+    StepStatus::CaveIn,
+    StepStatus::Dud,
+    StepStatus::Nft,
+    StepStatus::Exploded,
+];
+
+fn left_grid() -> impl Strategy<Value = [(StepStatus, u8); 8]> {
+    prop::array::uniform8(0u8..=20).prop_map(|counts| {
+        let mut left = [(StepStatus::Empty, 0); 8];
+        for (i, n) in counts.into_iter().enumerate() {
+            left[i] = (OUTCOMES[i], n);
+        }
+        left
+    })
+}
+
+proptest! {
+    #[test]
+    fn draw_never_picks_exhausted_outcome(left in left_grid(), random in any::<u64>()) {
+        match draw_step_status(&left, random) {
+            None => prop_assert_eq!(left_all_grid(&left), 0),
+            Some(status) => prop_assert!(left.iter().any(|(s, n)| *s == status && *n > 0)),
+        }
+    }
+
+    #[test]
+    fn draw_matches_advertised_odds(left in left_grid(), base in 0..u64::MAX / 2) {
+        let total = left_all_grid(&left);
+        prop_assume!(total > 0);
+
+        // every residue of one full cycle, each outcome must be hit exactly n times
+        let start = base / total * total;
+        let mut hits = [0u64; 8];
+        for random in start..start + total {
+            let status = draw_step_status(&left, random).unwrap();
+            let i = OUTCOMES.iter().position(|s| *s == status).unwrap();
+            hits[i] += 1;
+        }
+        for (i, (_, n)) in left.iter().enumerate() {
+            prop_assert_eq!(hits[i], *n as u64);
+        }
+    }
+
+    #[test]
+    fn game_counters_never_underflow(
+        grid in prop::array::uniform7(0u8..=15),
+        randoms in prop::collection::vec(any::<u64>(), 1..120),
+    ) {
+        let pool = Pool {
+            gold_grid: grid[0],
+            bomb_grid: grid[1],
+            gem_grid: grid[2],
+            chest_grid: grid[3],
+            cave_in_grid: grid[4],
+            dud_grid: grid[5],
+            nft_grid: grid[6],
+            gem_multiplier: 2,
+            allocation_value: 10,
+            available_token_amount: 1_000,
+            available_bonus_amount: 100,
+            ..Default::default()
+        };
+        prop_assume!(pool.special_grid() < pool.total_grid());
+
+        let mut game = GameInfo::default();
+        for random in randoms {
+            let left = game.left_grid(&pool);
+            prop_assert!(left.is_ok());
+            if left_all_grid(&left.unwrap()) == 0 {
+                break;
+            }
+            let status = game.handle_vrf(&pool, random, 0).unwrap();
+
+            prop_assert!(game.mined_empty_grid <= pool.empty_grid());
+            prop_assert!(game.mined_gold_grid <= pool.gold_grid);
+            prop_assert!(game.mined_gem_grid <= pool.gem_grid);
+            prop_assert!(game.mined_chest_grid <= pool.chest_grid);
+            prop_assert!(game.mined_cave_in_grid <= pool.cave_in_grid);
+            prop_assert!(game.mined_dud_grid <= pool.dud_grid);
+            prop_assert!(game.mined_nft_grid <= pool.nft_grid);
+            if status == StepStatus::Exploded {
+                prop_assert_eq!(game.game_status, GameStatus::Exploded);
+                break;
+            }
+        }
+    }
+}
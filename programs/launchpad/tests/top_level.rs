@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program, pubkey,
+    sysvar::instructions::{
+        self, construct_instructions_data, store_current_index, BorrowedAccountMeta,
+        BorrowedInstruction,
+    },
+};
+use launchpad::utils::check_sibling_instructions;
+
+const COMPUTE_BUDGET_ID: Pubkey = pubkey!("ComputeBudget111111111111111111111111111111");
+
+// runs check_sibling_instructions as instruction current of programs
+fn check(programs: &[Pubkey], current: u16) -> Result<()> {
+    let game_info = Pubkey::new_unique();
+    let ixs: Vec<_> = programs
+        .iter()
+        .map(|program_id| BorrowedInstruction {
+            program_id,
+            accounts: vec![BorrowedAccountMeta {
+                pubkey: &game_info,
+                is_signer: false,
+                is_writable: true,
+            }],
+            data: &[],
+        })
+        .collect();
+    let mut data = construct_instructions_data(&ixs);
+    store_current_index(&mut data, current);
+
+    let key = instructions::ID;
+    let owner = Pubkey::default();
+    let mut lamports = 0;
+    let info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    check_sibling_instructions(&info)
+}
+
+#[test]
+fn mine_alone_passes() {
+    assert!(check(&[launchpad::ID], 0).is_ok());
+}
+
+#[test]
+fn compute_budget_and_attestation_pass() {
+    let programs = [COMPUTE_BUDGET_ID, ed25519_program::ID, launchpad::ID];
+    assert!(check(&programs, 2).is_ok());
+}
+
+#[test]
+fn trailing_instruction_of_another_program_fails() {
+    let programs = [launchpad::ID, Pubkey::new_unique()];
+    assert!(check(&programs, 0).is_err());
+}
+
+#[test]
+fn leading_instruction_of_another_program_fails() {
+    let programs = [Pubkey::new_unique(), launchpad::ID];
+    assert!(check(&programs, 1).is_err());
+}
+
+#[test]
+fn second_launchpad_instruction_fails() {
+    let programs = [launchpad::ID, launchpad::ID];
+    assert!(check(&programs, 0).is_err());
+}
+
+#[test]
+fn attestation_after_mine_fails() {
+    let programs = [launchpad::ID, ed25519_program::ID];
+    assert!(check(&programs, 0).is_err());
+}
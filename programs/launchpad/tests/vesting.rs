@@ -0,0 +1,75 @@
+use launchpad::state::*;
+use proptest::prelude::*;
+
+const HOUR: i64 = 3_600;
+const DAY: i64 = 86_400;
+
+fn vesting_pool(cliff: u32, duration: u32) -> Pool {
+    Pool {
+        vesting_cliff: cliff,
+        vesting_duration: duration,
+        ..Default::default()
+    }
+}
+
+fn claim(vesting: &mut Vesting, cur: i64) -> u64 {
+    vesting.claim(cur).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn claims_add_up_to_deposits(
+        schedule in (0u32..=30, 0u32..=60),
+        ops in prop::collection::vec((0..10 * DAY, 0..1_000_000_000_000u64, any::<bool>()), 1..50),
+    ) {
+        let (cliff_days, extra_days) = schedule;
+        let cliff = cliff_days * DAY as u32;
+        let duration = cliff + extra_days * DAY as u32;
+        let pool = vesting_pool(cliff, duration);
+        let mut vesting = Vesting::default();
+        let (mut cur, mut deposited, mut claimed) = (0i64, 0u64, 0u64);
+        for (dt, amt, do_claim) in ops {
+            cur += dt;
+            vesting.add_amount(&pool, amt, cur).unwrap();
+            deposited += amt;
+            prop_assert!(vesting.cliff_ts <= vesting.end_ts);
+            prop_assert!(vesting.end_ts <= cur + duration as i64);
+            if do_claim {
+                claimed += claim(&mut vesting, cur);
+            }
+            prop_assert!(claimed + vesting.get_claimable_amt(cur).unwrap() <= deposited);
+        }
+        claimed += claim(&mut vesting, cur + duration as i64);
+        prop_assert_eq!(claimed, deposited);
+    }
+}
+
+#[test]
+fn steady_deposits_do_not_reset_earlier_rewards() {
+    let pool = vesting_pool(7 * DAY as u32, 14 * DAY as u32);
+    let mut vesting = Vesting::default();
+    let mut deposited_first_fortnight = 0;
+    let mut claimed = 0;
+    let mut cur = 0;
+    while cur < 30 * DAY {
+        vesting.add_amount(&pool, 1_000, cur).unwrap();
+        if cur < 14 * DAY {
+            deposited_first_fortnight += 1_000;
+        }
+        claimed += claim(&mut vesting, cur);
+        cur += HOUR;
+    }
+    assert!(claimed >= deposited_first_fortnight);
+}
+
+#[test]
+fn single_deposit_unlocks_linearly_after_cliff() {
+    let pool = vesting_pool(10, 100);
+    let mut vesting = Vesting::default();
+    vesting.add_amount(&pool, 1_000, 0).unwrap();
+    assert_eq!(vesting.get_claimable_amt(9).unwrap(), 0);
+    assert_eq!(vesting.get_claimable_amt(10).unwrap(), 100);
+    assert_eq!(claim(&mut vesting, 50), 500);
+    assert_eq!(claim(&mut vesting, 100), 500);
+    assert_eq!(claim(&mut vesting, 200), 0);
+}